@@ -1,35 +1,151 @@
-use crate::{AI, evaluate_immediate, pick_best_move, Score};
+use std::cell::Cell;
+
+use crate::{AI, AIInfo, DiscEvaluation, Evaluation, pick_best_move, score_moves, Score};
 use othello_game::{Board, Colour, Game, Move};
 
 #[derive(Clone)]
-pub struct AlphaBetaAI {
+pub struct AlphaBetaAI<E: Evaluation = DiscEvaluation> {
     pub max_depth: usize,
+    evaluation: E,
+    info: AIInfo,
 }
 
-impl AI for AlphaBetaAI {
-    fn choose_move<B: Board>(&self, game: &Game<B>) -> Option<Move> {
-        pick_best_move(game, |g, m| evaluate_to_depth(
-            &g.apply(m),
-            game.next_turn,
+impl AlphaBetaAI {
+    pub fn new(max_depth: usize) -> Self {
+        AlphaBetaAI::with_evaluation(max_depth, DiscEvaluation)
+    }
+}
+
+impl<E: Evaluation> AlphaBetaAI<E> {
+    /**
+     * Create an AI which evaluates the positions at the leaves of its search with `evaluation`.
+     */
+    pub fn with_evaluation(max_depth: usize, evaluation: E) -> Self {
+        let info = AIInfo::default();
+        AlphaBetaAI { max_depth, evaluation, info }
+    }
+
+    /**
+     * Score each of the current player's valid moves, as `choose_move` would when picking one.
+     */
+    pub fn score_moves<B: Board>(&self, game: &Game<B>) -> Vec<(Move, Score)> {
+        score_moves(game, |g, m| self.evaluate_move(g, m, &NeverStop))
+    }
+
+    /**
+     * Choose a move as `choose_move` does, but call `should_stop` periodically during the search,
+     * abandoning the search if it returns true.  The best of the moves completely searched so far
+     * is then returned, or None if no move had been completely searched.
+     */
+    pub fn choose_move_cancellable<B: Board, F>(&self, game: &Game<B>, should_stop: F) -> Option<Move>
+    where F: Fn() -> bool {
+        let stop = PollStop::new(should_stop);
+        let mut best: Option<(Move, Score)> = None;
+        for mov in game.valid_moves(game.next_turn) {
+            let score = self.evaluate_move(game, mov, &stop);
+            if stop.stopped.get() { break }
+
+            /* Prefer later moves on equal scores, as pick_best_move does. */
+            if best.is_none_or(|(_, best_score)| score >= best_score) {
+                best = Some((mov, score));
+            }
+        }
+        best.map(|(mov, _)| mov)
+    }
+
+    fn evaluate_move<B: Board, S: StopCondition>(&self, game: &Game<B>, mov: Move, stop: &S) -> Score {
+        /* The resulting position is scored from the opponent's point of view, as they move next. */
+        let game2 = game.apply(mov);
+        -evaluate_to_depth(
+            &game2,
+            game2.next_turn,
             -1_000_000,
             1_000_000,
-            self.max_depth))
+            self.max_depth,
+            self,
+            stop)
     }
 }
 
-fn evaluate_to_depth<B: Board>(game: &Game<B>, player: Colour, mut alpha: Score, beta: Score, depth: usize) -> Score {
-    if depth == 0 {
-        evaluate_immediate(game, player)
-    } else {
-        /* Evaluate this position as if the opponent will make its best available move. */
-        let opponent = player.opponent();
-        for mov in game.valid_moves(player) {
-            let g = game.apply(mov);
-            let score = -evaluate_to_depth(&g, opponent, -beta, -alpha, depth - 1);
-            if score >= beta { return beta }
-            if score > alpha { alpha = score }
+impl<E: Evaluation> AI for AlphaBetaAI<E> {
+    fn choose_move<B: Board>(&self, game: &Game<B>) -> Option<Move> {
+        pick_best_move(game, |g, m| self.evaluate_move(g, m, &NeverStop))
+    }
+
+    fn info(&self) -> Option<&AIInfo> {
+        Some(&self.info)
+    }
+}
+
+/**
+ * Checked at every node of the search; once it returns true, the search unwinds and its results are
+ * meaningless.
+ */
+trait StopCondition {
+    fn should_stop(&self) -> bool;
+}
+
+struct NeverStop;
+
+impl StopCondition for NeverStop {
+    #[inline(always)]
+    fn should_stop(&self) -> bool { false }
+}
+
+/* Only poll every so many nodes, as polling may be much slower than searching a node. */
+const NODES_PER_POLL: usize = 1024;
+
+struct PollStop<F: Fn() -> bool> {
+    poll: F,
+    nodes: Cell<usize>,
+    stopped: Cell<bool>,
+}
+
+impl<F: Fn() -> bool> PollStop<F> {
+    fn new(poll: F) -> Self {
+        PollStop { poll, nodes: Cell::new(0), stopped: Cell::new(false) }
+    }
+}
+
+impl<F: Fn() -> bool> StopCondition for PollStop<F> {
+    fn should_stop(&self) -> bool {
+        if !self.stopped.get() {
+            let nodes = self.nodes.get();
+            self.nodes.set(nodes + 1);
+            if nodes.is_multiple_of(NODES_PER_POLL) && (self.poll)() {
+                self.stopped.set(true);
+            }
         }
+        self.stopped.get()
+    }
+}
+
+fn evaluate_to_depth<B: Board, E: Evaluation, S: StopCondition>(game: &Game<B>, player: Colour, mut alpha: Score, beta: Score, depth: usize, ai: &AlphaBetaAI<E>, stop: &S) -> Score {
+    if stop.should_stop() { return 0 }
+    ai.info.add_node();
+
+    if depth == 0 {
+        return ai.evaluation.evaluate(game, player);
+    }
 
+    /* Evaluate this position as if the player will make its best available move. */
+    let opponent = player.opponent();
+    let mut any_moves = false;
+    for mov in game.valid_moves(player) {
+        any_moves = true;
+        let g = game.apply(mov);
+        let score = -evaluate_to_depth(&g, opponent, -beta, -alpha, depth - 1, ai, stop);
+        if score >= beta { return beta }
+        if score > alpha { alpha = score }
+    }
+
+    if any_moves {
         alpha
+    } else if game.valid_moves(opponent).into_iter().next().is_none() {
+        /* Neither player can move, so the game is over. */
+        ai.evaluation.evaluate(game, player)
+    } else {
+        /* Pass, leaving the board unchanged for the opponent.  They can move, so a pass doesn't use up depth. */
+        -evaluate_to_depth(game, opponent, -beta, -alpha, depth, ai, stop)
     }
 }
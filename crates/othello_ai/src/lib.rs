@@ -1,15 +1,25 @@
 mod alphabeta;
 mod immediate;
+mod mcts;
 pub mod minimax;
+mod mobility;
+mod playout;
 mod random;
+mod solver;
+mod stability;
 
 use std::sync::atomic::{AtomicUsize, Ordering};
-use othello_game::{Colour, Board, Game, Move, Score};
+use othello_game::{Colour, Board, Game, Move, Pos, Score};
 
 pub use alphabeta::AlphaBetaAI;
 pub use immediate::ImmediateAI;
+pub use mcts::MctsAI;
 pub use minimax::MinimaxAI;
-pub use random::RandomAI;
+pub use mobility::MobilityAI;
+pub use playout::random_playout;
+pub use random::{RandomAI, WeightedRandomAI};
+pub use solver::{can_force_win, solve};
+pub use stability::{evaluate_stability, stable_counts, stable_discs};
 
 /**
  * Evaluate this immediate othello_game position, returning a `Score`.  A higher score is considered
@@ -23,6 +33,150 @@ pub fn evaluate_immediate<B: Board>(game: &Game<B>, player: Colour) -> Score {
     score * player.sign()
 }
 
+/**
+ * Weights for each square by its (Manhattan) distance from the centre of the board, from 1 for the
+ * four centre squares to 7 for the corners.
+ */
+pub const CENTRE_DISTANCE_WEIGHTS: [[Score; 8]; 8] = [
+    [7, 6, 5, 4, 4, 5, 6, 7],
+    [6, 5, 4, 3, 3, 4, 5, 6],
+    [5, 4, 3, 2, 2, 3, 4, 5],
+    [4, 3, 2, 1, 1, 2, 3, 4],
+    [4, 3, 2, 1, 1, 2, 3, 4],
+    [5, 4, 3, 2, 2, 3, 4, 5],
+    [6, 5, 4, 3, 3, 4, 5, 6],
+    [7, 6, 5, 4, 4, 5, 6, 7],
+];
+
+/**
+ * Sum the `CENTRE_DISTANCE_WEIGHTS` of the given player's pieces minus those of the opponent's, so
+ * that pieces towards the edges and corners count for more than those in the centre.
+ */
+pub fn evaluate_centre_distance<B: Board>(game: &Game<B>, player: Colour) -> Score {
+    weighted_pieces(game, player, &CENTRE_DISTANCE_WEIGHTS)
+}
+
+/* Sum the weights of the squares holding the given player's pieces minus those of the opponent's. */
+fn weighted_pieces<B: Board>(game: &Game<B>, player: Colour, square_weights: &[[Score; 8]; 8]) -> Score {
+    let mut score = 0;
+    for (row, weights) in square_weights.iter().enumerate() {
+        for (col, weight) in weights.iter().enumerate() {
+            match game.get_piece(row as Pos, col as Pos) {
+                Some(colour) if colour == player => score += weight,
+                Some(_) => score -= weight,
+                None => (),
+            }
+        }
+    }
+    score
+}
+
+/**
+ * The classic weights for each square by its strategic value: corners are worth most, the edge
+ * squares next to them (C-squares) and especially the diagonal squares next to them (X-squares) are
+ * negative, as they tend to give corners away, and the rest of the edges are worth a little.
+ */
+pub const POSITIONAL_WEIGHTS: [[Score; 8]; 8] = [
+    [100, -20, 10,  5,  5, 10, -20, 100],
+    [-20, -50, -2, -2, -2, -2, -50, -20],
+    [ 10,  -2, -1, -1, -1, -1,  -2,  10],
+    [  5,  -2, -1, -1, -1, -1,  -2,   5],
+    [  5,  -2, -1, -1, -1, -1,  -2,   5],
+    [ 10,  -2, -1, -1, -1, -1,  -2,  10],
+    [-20, -50, -2, -2, -2, -2, -50, -20],
+    [100, -20, 10,  5,  5, 10, -20, 100],
+];
+
+/**
+ * Sum the `POSITIONAL_WEIGHTS` of the given player's pieces minus those of the opponent's.
+ */
+pub fn evaluate_positional<B: Board>(game: &Game<B>, player: Colour) -> Score {
+    weighted_pieces(game, player, &POSITIONAL_WEIGHTS)
+}
+
+/**
+ * Count the corners held by the given player minus those held by the opponent.
+ */
+pub fn evaluate_corners<B: Board>(game: &Game<B>, player: Colour) -> Score {
+    [(0, 0), (0, 7), (7, 0), (7, 7)].iter()
+        .filter_map(|&(row, col)| game.get_piece(row, col))
+        .map(|colour| if colour == player { 1 } else { -1 })
+        .sum()
+}
+
+/**
+ * Count the valid moves the given player has minus those the opponent has.
+ */
+pub fn evaluate_mobility<B: Board>(game: &Game<B>, player: Colour) -> Score {
+    let own_moves = game.valid_moves(player).into_iter().count() as Score;
+    let opponent_moves = game.valid_moves(player.opponent()).into_iter().count() as Score;
+    own_moves - opponent_moves
+}
+
+/**
+ * The parts making up an `Evaluation` of a position, as given by `Evaluation::components`, so that
+ * `total` is the evaluation itself.  Any part the evaluation doesn't take into account is 0.
+ */
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct EvaluationComponents {
+    pub corners: Score,
+    pub mobility: Score,
+    pub discs: Score,
+    pub stability: Score,
+}
+
+impl EvaluationComponents {
+    pub fn total(&self) -> Score {
+        self.corners + self.mobility + self.discs + self.stability
+    }
+}
+
+/**
+ * A static evaluation of a othello_game position, as used at the leaves of a search.  As for
+ * `evaluate_immediate`, evaluation is from the point of view of the given player, with higher scores
+ * being better.
+ */
+pub trait Evaluation: Clone + Send {
+    fn evaluate<B: Board>(&self, game: &Game<B>, player: Colour) -> Score;
+
+    /**
+     * Break down `evaluate` for the same position and player into the parts it adds up.
+     */
+    fn components<B: Board>(&self, game: &Game<B>, player: Colour) -> EvaluationComponents;
+}
+
+/**
+ * Evaluates positions with `evaluate_immediate`, i.e. by piece count.
+ */
+#[derive(Clone, Default)]
+pub struct DiscEvaluation;
+
+impl Evaluation for DiscEvaluation {
+    fn evaluate<B: Board>(&self, game: &Game<B>, player: Colour) -> Score {
+        evaluate_immediate(game, player)
+    }
+
+    fn components<B: Board>(&self, game: &Game<B>, player: Colour) -> EvaluationComponents {
+        EvaluationComponents { discs: evaluate_immediate(game, player), ..Default::default() }
+    }
+}
+
+/**
+ * Evaluates positions with `evaluate_corners`, ignoring everything but the corners.
+ */
+#[derive(Clone, Default)]
+pub struct CornerEvaluation;
+
+impl Evaluation for CornerEvaluation {
+    fn evaluate<B: Board>(&self, game: &Game<B>, player: Colour) -> Score {
+        evaluate_corners(game, player)
+    }
+
+    fn components<B: Board>(&self, game: &Game<B>, player: Colour) -> EvaluationComponents {
+        EvaluationComponents { corners: evaluate_corners(game, player), ..Default::default() }
+    }
+}
+
 /**
  * Pick the best move in the othello_game, for the current player, using the given evaluation function.
  * This will pick the move with the highest score (as calculated by the evaluation function on the
@@ -35,6 +189,16 @@ where F: Fn(&Game<B>, Move) -> Score {
     game.valid_moves(game.next_turn).into_iter().max_by_key(|m| evaluate_move(game, *m))
 }
 
+/**
+ * Score every valid move in the othello_game, for the current player, using the given evaluation
+ * function, as `pick_best_move` does.  Moves are returned in the order they are generated, so the
+ * move `pick_best_move` would pick is the last one with the highest score.
+ */
+pub fn score_moves<B: Board, F>(game: &Game<B>, evaluate_move: F) -> Vec<(Move, Score)>
+where F: Fn(&Game<B>, Move) -> Score {
+    game.valid_moves(game.next_turn).into_iter().map(|m| (m, evaluate_move(game, m))).collect()
+}
+
 pub trait AI: Clone + Send {
     fn choose_move<B: Board>(&self, game: &Game<B>) -> Option<Move>;
     fn info(&self) -> Option<&AIInfo> { None }
@@ -0,0 +1,136 @@
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+
+use crate::{AI, random_playout};
+use othello_game::{Board, Colour, convert, Game, Move};
+use othello_game::bitboardgame::BitBoardBoard;
+
+/* How strongly UCT favours exploring less visited children over exploiting the best ones. */
+const EXPLORATION: f64 = std::f64::consts::SQRT_2;
+
+struct Node {
+    game: Game<BitBoardBoard>,
+    /* The move leading to this node from its parent, or None for a pass (or the root). */
+    mov: Option<Move>,
+    /* The player who made that move, from whose point of view `wins` is counted. */
+    player: Colour,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    untried: Vec<Option<Move>>,
+    visits: usize,
+    wins: f64,
+}
+
+impl Node {
+    fn new(game: Game<BitBoardBoard>, mov: Option<Move>, player: Colour, parent: Option<usize>) -> Node {
+        let mut untried: Vec<Option<Move>> = game.valid_moves(game.next_turn).map(Some).collect();
+        if untried.is_empty() && game.valid_moves(game.next_turn.opponent()).next().is_some() {
+            untried.push(None);
+        }
+        Node { game, mov, player, parent, children: Vec::new(), untried, visits: 0, wins: 0.0 }
+    }
+
+    fn uct(&self, parent_visits: usize) -> f64 {
+        let visits = self.visits as f64;
+        self.wins / visits + EXPLORATION * ((parent_visits as f64).ln() / visits).sqrt()
+    }
+}
+
+/**
+ * An AI using Monte Carlo Tree Search.  Each of `iterations` iterations walks down the tree choosing
+ * children by UCT (upper confidence bounds applied to trees), adds a new child, plays the game out
+ * from there with random moves, and records the result in every node on the way back up.  The move
+ * visited most often is chosen.
+ *
+ * Random play is seeded by `seed` if given, so that the same move is chosen every time.
+ */
+#[derive(Clone)]
+pub struct MctsAI {
+    pub iterations: usize,
+    pub seed: Option<u64>,
+}
+
+impl MctsAI {
+    pub fn new(iterations: usize, seed: Option<u64>) -> MctsAI {
+        MctsAI { iterations, seed }
+    }
+
+    /**
+     * Search the othello_game and return the number of times each valid move for the current player
+     * was visited, in the order they are generated.
+     */
+    pub fn visit_counts<B: Board>(&self, game: &Game<B>) -> Vec<(Move, usize)> {
+        let tree = self.search(convert(game));
+        game.valid_moves(game.next_turn).into_iter()
+            .map(|m| {
+                let visits = tree[0].children.iter()
+                    .find(|&&child| tree[child].mov == Some(m))
+                    .map_or(0, |&child| tree[child].visits);
+                (m, visits)
+            })
+            .collect()
+    }
+
+    fn search(&self, game: Game<BitBoardBoard>) -> Vec<Node> {
+        let mut rng = match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+        let player = game.next_turn.opponent();
+        let mut tree = vec![Node::new(game, None, player, None)];
+
+        for _ in 0..self.iterations {
+            /* Selection */
+            let mut node = 0;
+            while tree[node].untried.is_empty() && !tree[node].children.is_empty() {
+                let parent_visits = tree[node].visits;
+                node = *tree[node].children.iter()
+                    .max_by(|&&a, &&b| tree[a].uct(parent_visits).total_cmp(&tree[b].uct(parent_visits)))
+                    .expect("some children");
+            }
+
+            /* Expansion */
+            if !tree[node].untried.is_empty() {
+                let i = rng.gen_range(0..tree[node].untried.len());
+                let mov = tree[node].untried.swap_remove(i);
+                let parent = &tree[node].game;
+                let (child_game, child_player) = match mov {
+                    Some(mov) => (parent.apply(mov), mov.player),
+                    None => {
+                        let mut passed = parent.clone();
+                        passed.next_turn = parent.next_turn.opponent();
+                        (passed, parent.next_turn)
+                    }
+                };
+                tree.push(Node::new(child_game, mov, child_player, Some(node)));
+                let child = tree.len() - 1;
+                tree[node].children.push(child);
+                node = child;
+            }
+
+            /* Simulation */
+            let (end, _) = random_playout(&tree[node].game, &mut rng);
+            let (black_count, white_count) = end.scores();
+
+            /* Backpropagation */
+            let mut current = Some(node);
+            while let Some(n) = current {
+                let margin = (black_count - white_count) * tree[n].player.sign();
+                tree[n].visits += 1;
+                tree[n].wins += match margin.signum() {
+                    1 => 1.0,
+                    0 => 0.5,
+                    _ => 0.0,
+                };
+                current = tree[n].parent;
+            }
+        }
+        tree
+    }
+}
+
+impl AI for MctsAI {
+    fn choose_move<B: Board>(&self, game: &Game<B>) -> Option<Move> {
+        self.visit_counts(game).into_iter().max_by_key(|(_, visits)| *visits).map(|(m, _)| m)
+    }
+}
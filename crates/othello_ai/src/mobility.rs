@@ -0,0 +1,27 @@
+use crate::{AI, pick_best_move, Score};
+use othello_game::{Board, Colour, Game, Move};
+
+/**
+ * An AI that restricts its opponent's mobility, looking one move ahead.  It picks the move leaving
+ * the opponent the fewest valid replies, and breaks ties by flipping as few pieces as possible (the
+ * classic "low disc" strategy).
+ */
+#[derive(Clone)]
+pub struct MobilityAI {
+}
+
+impl AI for MobilityAI {
+    fn choose_move<B: Board>(&self, game: &Game<B>) -> Option<Move> {
+        fn evaluate_move<B: Board>(game: &Game<B>, mov: Move) -> Score {
+            let game2 = game.apply(mov);
+            let replies = game2.valid_moves(game2.next_turn).into_iter().count() as Score;
+            let (black_count, white_count) = game2.scores();
+            let own_count = if mov.player == Colour::Black { black_count } else { white_count };
+
+            /* Each reply outweighs any number of flips, which are at most 64. */
+            -(replies * 100 + own_count)
+        }
+
+        pick_best_move(game, evaluate_move)
+    }
+}
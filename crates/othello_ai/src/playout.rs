@@ -0,0 +1,25 @@
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+use othello_game::{Board, Game, Move};
+
+/**
+ * Play the othello_game out to the end with random moves for both players, using the given random
+ * number generator.  A player with no valid moves passes, and the game ends when neither player can
+ * move.  Returns the final position, and the number of plies played, counting each pass as a ply.
+ */
+pub fn random_playout<B: Board + Clone, R: Rng>(game: &Game<B>, rng: &mut R) -> (Game<B>, usize) {
+    let mut game = game.clone();
+    let mut plies = 0;
+    loop {
+        let moves: Vec<Move> = game.valid_moves(game.next_turn).into_iter().collect();
+        if let Some(mov) = moves.choose(rng) {
+            game = game.apply(*mov);
+        } else if game.valid_moves(game.next_turn.opponent()).into_iter().next().is_some() {
+            game.next_turn = game.next_turn.opponent();
+        } else {
+            return (game, plies);
+        }
+        plies += 1;
+    }
+}
@@ -1,7 +1,8 @@
+use rand::Rng;
 use rand::seq::SliceRandom;
 
 use crate::AI;
-use othello_game::{Board, Game, Move};
+use othello_game::{Board, Colour, Game, Move};
 
 #[derive(Clone)]
 pub struct RandomAI {
@@ -13,3 +14,60 @@ impl AI for RandomAI {
         moves.choose(&mut rand::thread_rng()).copied()
     }
 }
+
+/**
+ * An AI that plays randomly, but favours moves flipping more pieces.  Each move is chosen with
+ * probability in proportion to `exp(flips / temperature)`, i.e. a softmax of the flips, so a high
+ * temperature plays almost uniformly at random, and a temperature near zero (or below) always
+ * flips as many pieces as possible, like `ImmediateAI`.
+ */
+#[derive(Clone)]
+pub struct WeightedRandomAI {
+    pub temperature: f64,
+}
+
+impl WeightedRandomAI {
+    /**
+     * Choose a move as `choose_move` does, using the given random number generator, so that play
+     * can be reproduced.
+     */
+    pub fn choose_move_with_rng<B: Board, R: Rng>(&self, game: &Game<B>, rng: &mut R) -> Option<Move> {
+        let moves: Vec<Move> = game.valid_moves(game.next_turn).into_iter().collect();
+        let player = game.next_turn;
+        let own_count = |game: &Game<B>| {
+            let (black_count, white_count) = game.scores();
+            if player == Colour::Black { black_count } else { white_count }
+        };
+        /* The placed piece isn't a flip. */
+        let flips: Vec<f64> = moves.iter()
+            .map(|m| (own_count(&game.apply(*m)) - own_count(game) - 1) as f64)
+            .collect();
+        let most_flips = flips.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+        /* Subtracting the most flips keeps the weights in range without changing the probabilities. */
+        let weights: Vec<f64> = flips.iter()
+            .map(|&f| {
+                if self.temperature > 0.0 { ((f - most_flips) / self.temperature).exp() }
+                else if f == most_flips { 1.0 }
+                else { 0.0 }
+            })
+            .collect();
+
+        let total: f64 = weights.iter().sum();
+        let mut target = rng.gen::<f64>() * total;
+        for (mov, weight) in moves.iter().zip(&weights) {
+            if target < *weight {
+                return Some(*mov);
+            }
+            target -= weight;
+        }
+        /* Rounding can leave a little of the target over, so settle for the last best move. */
+        moves.iter().zip(&flips).rev().find(|(_, &f)| f == most_flips).map(|(m, _)| *m)
+    }
+}
+
+impl AI for WeightedRandomAI {
+    fn choose_move<B: Board>(&self, game: &Game<B>) -> Option<Move> {
+        self.choose_move_with_rng(game, &mut rand::thread_rng())
+    }
+}
@@ -0,0 +1,73 @@
+use othello_game::{Board, Colour, convert, Game, Score};
+use othello_game::bitboardgame::BitBoardBoard;
+
+/* Final disc differences lie within -64..=64, so this window never cuts off an exact result. */
+const SOLVE_BOUND: Score = 65;
+
+/**
+ * Solve the othello_game exactly, by searching every line of play to the end of the game.  Returns the
+ * final disc difference (friendly pieces minus enemy pieces) from the point of view of the player to
+ * move, assuming both players play perfectly.  A player with no valid moves passes, and the game ends
+ * when neither player can move.
+ *
+ * The cost grows exponentially with the number of empty squares, so this is only practical close to
+ * the end of the game.
+ */
+pub fn solve<B: Board>(game: &Game<B>) -> Score {
+    let game: Game<BitBoardBoard> = convert(game);
+    solve_board(&game.board, game.next_turn, -SOLVE_BOUND, SOLVE_BOUND)
+}
+
+fn solve_board<B: Board>(board: &B, player: Colour, mut alpha: Score, beta: Score) -> Score {
+    let opponent = player.opponent();
+    let mut any_moves = false;
+    for mov in board.moves(player) {
+        any_moves = true;
+        let score = -solve_board(&board.apply(mov), opponent, -beta, -alpha);
+        if score >= beta { return beta }
+        if score > alpha { alpha = score }
+    }
+
+    if any_moves {
+        alpha
+    } else if board.moves(opponent).into_iter().next().is_none() {
+        /* Neither player can move, so the game is over. */
+        let (black_count, white_count) = board.scores();
+        (black_count - white_count) * player.sign()
+    } else {
+        /* Pass, leaving the board unchanged for the opponent. */
+        -solve_board(board, opponent, -beta, -alpha)
+    }
+}
+
+/**
+ * Check whether the given player can force a win (finishing with more pieces than the opponent)
+ * within `max_plies` plies, whatever the opponent plays.  Passes count as plies.  Like `solve`,
+ * this is an exact search rather than a heuristic one; lines of play that haven't finished within
+ * `max_plies` plies don't count as wins.
+ */
+pub fn can_force_win<B: Board>(game: &Game<B>, player: Colour, max_plies: usize) -> bool {
+    let game: Game<BitBoardBoard> = convert(game);
+    force_win(&game.board, game.next_turn, player, max_plies)
+}
+
+fn force_win<B: Board>(board: &B, to_move: Colour, player: Colour, plies: usize) -> bool {
+    let opponent = to_move.opponent();
+    let moves: Vec<_> = board.moves(to_move).into_iter().collect();
+
+    if moves.is_empty() && board.moves(opponent).into_iter().next().is_none() {
+        let (black_count, white_count) = board.scores();
+        return (black_count - white_count) * player.sign() > 0;
+    }
+    if plies == 0 {
+        return false;
+    }
+
+    if moves.is_empty() {
+        force_win(board, opponent, player, plies - 1)
+    } else if to_move == player {
+        moves.into_iter().any(|m| force_win(&board.apply(m), opponent, player, plies - 1))
+    } else {
+        moves.into_iter().all(|m| force_win(&board.apply(m), opponent, player, plies - 1))
+    }
+}
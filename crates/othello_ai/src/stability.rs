@@ -0,0 +1,95 @@
+use othello_game::{Board, Colour, Game, Pos, Score};
+
+/* One direction along each of the four lines through a square: across, down and both diagonals. */
+const AXES: [(Pos, Pos); 4] = [(0, 1), (1, 0), (1, 1), (1, -1)];
+
+fn on_board(row: Pos, col: Pos) -> bool {
+    (0..8).contains(&row) && (0..8).contains(&col)
+}
+
+/**
+ * Check whether the whole line through a square in the given direction (both ways, to the edges of
+ * the board) is occupied.  Nothing can then be played on that line to flip the square along it.
+ */
+fn line_full<B: Board>(game: &Game<B>, row: Pos, col: Pos, (dy, dx): (Pos, Pos)) -> bool {
+    [(dy, dx), (-dy, -dx)].iter().all(|&(dy, dx)| {
+        let (mut r, mut c) = (row + dy, col + dx);
+        while on_board(r, c) {
+            if game.get_piece(r, c).is_none() {
+                return false;
+            }
+            r += dy;
+            c += dx;
+        }
+        true
+    })
+}
+
+/**
+ * Find the pieces that can never be flipped, whatever is played for the rest of the game, indexed
+ * by row then column.
+ *
+ * A piece is stable if, along each of the four lines through it, either the line is full, or one of
+ * its neighbours on the line is the edge of the board or a stable piece of the same colour.  Starting
+ * with no stable pieces, this is applied repeatedly until no more are found, so stability spreads
+ * out from the corners.  This finds most, but not necessarily all, stable pieces.
+ */
+pub fn stable_discs<B: Board>(game: &Game<B>) -> [[bool; 8]; 8] {
+    let mut stable = [[false; 8]; 8];
+    let is_anchor = |stable: &[[bool; 8]; 8], row: Pos, col: Pos, colour: Colour| {
+        !on_board(row, col) || (stable[row as usize][col as usize] && game.get_piece(row, col) == Some(colour))
+    };
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for row in 0..8 {
+            for col in 0..8 {
+                let Some(colour) = game.get_piece(row, col) else { continue };
+                if stable[row as usize][col as usize] {
+                    continue;
+                }
+
+                let now_stable = AXES.iter().all(|&(dy, dx)| {
+                    is_anchor(&stable, row + dy, col + dx, colour)
+                        || is_anchor(&stable, row - dy, col - dx, colour)
+                        || line_full(game, row, col, (dy, dx))
+                });
+                if now_stable {
+                    stable[row as usize][col as usize] = true;
+                    changed = true;
+                }
+            }
+        }
+    }
+    stable
+}
+
+/**
+ * Count the stable pieces (as found by `stable_discs`) of each colour, returning (black, white).
+ */
+pub fn stable_counts<B: Board>(game: &Game<B>) -> (Score, Score) {
+    let stable = stable_discs(game);
+    let (mut black_count, mut white_count) = (0, 0);
+    for row in 0..8 {
+        for col in 0..8 {
+            if !stable[row as usize][col as usize] {
+                continue;
+            }
+            match game.get_piece(row, col) {
+                Some(Colour::Black) => black_count += 1,
+                Some(Colour::White) => white_count += 1,
+                None => (),
+            }
+        }
+    }
+    (black_count, white_count)
+}
+
+/**
+ * Count the given player's stable pieces minus the opponent's.
+ */
+pub fn evaluate_stability<B: Board>(game: &Game<B>, player: Colour) -> Score {
+    let (black_count, white_count) = stable_counts(game);
+    (black_count - white_count) * player.sign()
+}
@@ -0,0 +1,102 @@
+use othello_ai::{AI, AlphaBetaAI, CornerEvaluation, DiscEvaluation, Evaluation, EvaluationComponents};
+use othello_game::{Colour, DefaultGame, Game, Move};
+
+#[test]
+fn test_score_moves_matches_choose_move() {
+    let mut game: Game = "\n\
+    ·●○○○\n\
+    ·○○\n\
+    ·○".try_into().expect("ok");
+    game.next_turn = Colour::White;
+
+    let ai = AlphaBetaAI::new(3);
+    let scored = ai.score_moves(&game);
+    assert_eq!(game.valid_moves(Colour::White).len(), scored.len());
+
+    let best_score = scored.iter().map(|(_, score)| *score).max().expect("some moves");
+    let best_move = scored.iter().rev().find(|(_, score)| *score == best_score).map(|(m, _)| *m);
+    assert_eq!(ai.choose_move(&game), best_move);
+}
+
+#[test]
+fn test_cancellable() {
+    let game = DefaultGame::new();
+    let ai = AlphaBetaAI::new(3);
+
+    assert_eq!(ai.choose_move(&game), ai.choose_move_cancellable(&game, || false));
+    assert_eq!(None, ai.choose_move_cancellable(&game, || true));
+}
+
+#[test]
+fn test_disc_evaluation_is_zero_sum() {
+    let game: Game = "·●○○○\n\
+    ·○○\n\
+    ·○".try_into().expect("ok");
+
+    /* Without searching, the AI scores each move by the evaluation of the position it leads to. */
+    let ai = AlphaBetaAI::new(0);
+    let scored = ai.score_moves(&game);
+    assert!(!scored.is_empty());
+    for (mov, score) in scored {
+        let after = game.apply(mov);
+        assert_eq!(score, DiscEvaluation.evaluate(&after, Colour::Black));
+        assert_eq!(-score, DiscEvaluation.evaluate(&after, Colour::White));
+    }
+}
+
+#[test]
+fn test_components_total() {
+    let game: Game = "○○●".try_into().expect("ok");
+
+    let components = DiscEvaluation.components(&game, Colour::Black);
+    assert_eq!(EvaluationComponents { discs: 1, ..Default::default() }, components);
+    assert_eq!(DiscEvaluation.evaluate(&game, Colour::Black), components.total());
+
+    let components = CornerEvaluation.components(&game, Colour::Black);
+    assert_eq!(EvaluationComponents { corners: 1, ..Default::default() }, components);
+    assert_eq!(CornerEvaluation.evaluate(&game, Colour::Black), components.total());
+}
+
+#[test]
+fn test_corner_evaluation() {
+    let game: Game = "\n\
+    ·●○\n\
+    ·●○".try_into().expect("ok");
+
+    /* Both moves flip one piece, but only one takes a corner. */
+    let ai = AlphaBetaAI::with_evaluation(0, CornerEvaluation);
+    assert_eq!(Some(Move { player: Colour::Black, row: 0, col: 0 }), ai.choose_move(&game));
+}
+
+#[test]
+fn test_avoids_capturing_reply() {
+    let game: Game = "●\n\
+    ○\n\
+    ●●●".try_into().expect("ok");
+
+    /* Both moves flip one piece, but a4 lets White flip three back, where c4 only allows one. */
+    let ai = AlphaBetaAI::new(1);
+    assert_eq!(Some(Move { player: Colour::Black, row: 3, col: 2 }), ai.choose_move(&game));
+}
+
+#[test]
+fn test_searches_past_pass() {
+    let game: Game = "·○\n\
+    ·●\n\
+    ○●".try_into().expect("ok");
+
+    /* After c1, White has to pass, and Black can then flip White's last piece. */
+    let ai = AlphaBetaAI::new(1);
+    assert_eq!(Some(Move { player: Colour::Black, row: 0, col: 2 }), ai.choose_move(&game));
+}
+
+#[test]
+fn test_passes_when_stuck() {
+    let game: Game = "○\n\
+    ○●●\n\
+    ○".try_into().expect("ok");
+
+    /* d2 wipes White out, leaving Black 6 discs, but after c1 White has to pass and Black's next move reaches 7. */
+    let ai = AlphaBetaAI::new(1);
+    assert_eq!(Some(Move { player: Colour::Black, row: 0, col: 2 }), ai.choose_move(&game));
+}
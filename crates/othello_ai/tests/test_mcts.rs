@@ -0,0 +1,23 @@
+use othello_ai::{AI, MctsAI};
+use othello_game::{Colour, DefaultGame, Game};
+
+#[test]
+fn test_seeded() {
+    let game = DefaultGame::new();
+    let ai = MctsAI::new(200, Some(3));
+
+    assert_eq!(ai.visit_counts(&game), ai.visit_counts(&game));
+    let total: usize = ai.visit_counts(&game).iter().map(|(_, visits)| visits).sum();
+    assert_eq!(200, total);
+}
+
+#[test]
+fn test_finds_win() {
+    let mut game: Game = "·○●○·".try_into().expect("ok");
+    game.next_turn = Colour::White;
+
+    /* Taking (0, 0) wipes out Black, but (0, 4) lets Black reply at (0, 5) and win. */
+    let ai = MctsAI::new(200, Some(1));
+    let mov = ai.choose_move(&game).expect("a move");
+    assert_eq!((0, 0), (mov.row, mov.col));
+}
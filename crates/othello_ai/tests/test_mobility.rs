@@ -0,0 +1,21 @@
+use othello_ai::{AI, MobilityAI};
+use othello_game::{DefaultGame, Game};
+
+fn replies(game: &Game) -> usize {
+    game.valid_moves(game.next_turn).len()
+}
+
+#[test]
+fn test_minimises_replies() {
+    let ai = MobilityAI {};
+    let mut game = DefaultGame::new();
+
+    while let Some(mov) = ai.choose_move(&game) {
+        let fewest_replies = game.valid_moves(game.next_turn).into_iter()
+            .map(|m| replies(&game.apply(m)))
+            .min();
+
+        game = game.apply(mov);
+        assert_eq!(fewest_replies, Some(replies(&game)));
+    }
+}
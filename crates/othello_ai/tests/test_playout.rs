@@ -0,0 +1,29 @@
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use othello_ai::random_playout;
+use othello_game::{Colour, DefaultGame, Game};
+
+#[test]
+fn test_plays_to_end() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let (game, plies) = random_playout(&DefaultGame::new(), &mut rng);
+
+    assert!(game.valid_moves(Colour::Black).is_empty());
+    assert!(game.valid_moves(Colour::White).is_empty());
+    /* Each placement adds a piece, and any passes are extra plies. */
+    let (black_count, white_count) = game.scores();
+    assert!(plies >= (black_count + white_count - 4) as usize);
+}
+
+#[test]
+fn test_pass() {
+    let mut game: Game = "○●·".try_into().expect("ok");
+    game.next_turn = Colour::White;
+    let mut rng = StdRng::seed_from_u64(1);
+
+    /* White passes, then Black fills the last square. */
+    let (game, plies) = random_playout(&game, &mut rng);
+    assert_eq!(2, plies);
+    assert_eq!((3, 0), game.scores());
+}
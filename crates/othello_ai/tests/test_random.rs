@@ -0,0 +1,35 @@
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use othello_ai::{AI, evaluate_immediate, ImmediateAI, WeightedRandomAI};
+use othello_game::DefaultGame;
+
+#[test]
+fn test_zero_temperature_is_greedy() {
+    let ai = WeightedRandomAI { temperature: 0.0 };
+    let mut game = DefaultGame::new();
+
+    while let Some(mov) = ai.choose_move(&game) {
+        let greedy = ImmediateAI {}.choose_move(&game).expect("a move");
+        assert_eq!(evaluate_immediate(&game.apply(greedy), game.next_turn),
+                   evaluate_immediate(&game.apply(mov), game.next_turn));
+        game = game.apply(mov);
+    }
+}
+
+#[test]
+fn test_seeded() {
+    let ai = WeightedRandomAI { temperature: 1.0 };
+    let play = |seed| {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut game = DefaultGame::new();
+        let mut moves = Vec::new();
+        while let Some(mov) = ai.choose_move_with_rng(&game, &mut rng) {
+            moves.push(mov);
+            game = game.apply(mov);
+        }
+        moves
+    };
+
+    assert_eq!(play(7), play(7));
+}
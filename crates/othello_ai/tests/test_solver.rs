@@ -0,0 +1,54 @@
+use othello_ai::{can_force_win, solve};
+use othello_game::{Colour, Game};
+
+#[test]
+fn test_finished_game() {
+    let game: Game = "○○○".try_into().expect("ok");
+
+    /* White has been wiped out, so neither player can move. */
+    assert_eq!(3, solve(&game));
+}
+
+#[test]
+fn test_last_move() {
+    let game: Game = "○●·".try_into().expect("ok");
+
+    /* Black fills the last square, flipping White's only piece. */
+    assert_eq!(3, solve(&game));
+}
+
+#[test]
+fn test_pass() {
+    let mut game: Game = "○●·".try_into().expect("ok");
+    game.next_turn = Colour::White;
+
+    /* White can't move, so passes, and Black then takes everything. */
+    assert_eq!(-3, solve(&game));
+}
+
+#[test]
+fn test_forced_sequence() {
+    let game: Game = "·○●○●○●○".try_into().expect("ok");
+
+    /* Black can't move, so White plays (0, 0) and fills the row, finishing five to three. */
+    assert_eq!(-2, solve(&game));
+}
+
+#[test]
+fn test_force_win() {
+    let game: Game = "○●·".try_into().expect("ok");
+
+    assert!(can_force_win(&game, Colour::Black, 1));
+    assert!(!can_force_win(&game, Colour::Black, 0));
+    assert!(!can_force_win(&game, Colour::White, 1));
+}
+
+#[test]
+fn test_force_win_after_pass() {
+    let mut game: Game = "○●·".try_into().expect("ok");
+    game.next_turn = Colour::White;
+
+    /* White's pass counts as a ply. */
+    assert!(!can_force_win(&game, Colour::Black, 1));
+    assert!(can_force_win(&game, Colour::Black, 2));
+}
@@ -0,0 +1,28 @@
+use othello_ai::{evaluate_stability, stable_counts, stable_discs};
+use othello_game::{Colour, DefaultGame, Game};
+
+#[test]
+fn test_start_position() {
+    let game = DefaultGame::new();
+
+    assert_eq!((0, 0), stable_counts(&game));
+}
+
+#[test]
+fn test_stable_from_corner() {
+    let game: Game = "○○●".try_into().expect("ok");
+
+    /* Black's corner anchors the piece next to it, but White's piece can still be flipped. */
+    let stable = stable_discs(&game);
+    assert!(stable[0][0] && stable[0][1]);
+    assert!(!stable[0][2]);
+    assert_eq!(2, evaluate_stability(&game, Colour::Black));
+    assert_eq!(-2, evaluate_stability(&game, Colour::White));
+}
+
+#[test]
+fn test_full_edge() {
+    let game: Game = "●○○○○○○●".try_into().expect("ok");
+
+    assert_eq!((6, 2), stable_counts(&game));
+}
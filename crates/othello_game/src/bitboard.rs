@@ -66,15 +66,6 @@ impl BitBoard {
         self.0.count_ones()
     }
 
-    pub(crate) fn next_bit(&self) -> BitBoard {
-        if self.0 == 0 {
-            return *self;
-        }
-        let new_val = self.0 & (self.0 - 1);
-        let lsb = self.0 - new_val;
-        BitBoard(lsb)
-    }
-
     pub(crate) fn pop_next_bit(&mut self) -> BitBoard {
         if self.0 == 0 {
             return *self;
@@ -255,10 +246,11 @@ mod test {
     use super::*;
 
     #[test]
-    fn test_next_bit() {
-        let bb = BitBoard::from(&[(0, 0), (0, 6)]);
-        let nb = bb.next_bit();
+    fn test_pop_next_bit() {
+        let mut bb = BitBoard::from(&[(0, 0), (0, 6)]);
+        let nb = bb.pop_next_bit();
         assert_eq!(BitBoard::from(&[(0, 0)]), nb);
+        assert_eq!(BitBoard::from((0, 6)), bb);
     }
 
     #[test]
@@ -1,8 +1,15 @@
+use std::cell::Cell;
+use std::sync::atomic::Ordering;
+use std::time::Instant;
+
 use pyo3::prelude::*;
-use pyo3::types::{PyList, PyTuple};
+use pyo3::types::{PyDict, PyList, PyTuple};
 use pyo3::exceptions::PyValueError;
+use rand::prelude::SliceRandom;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 
-use othello_ai::{AI, AlphaBetaAI, RandomAI};
+use othello_ai::{AI, AlphaBetaAI, can_force_win, CornerEvaluation, DiscEvaluation, Evaluation, evaluate_centre_distance, evaluate_mobility, evaluate_positional, evaluate_stability, MctsAI, MobilityAI, POSITIONAL_WEIGHTS, random_playout, solve, stable_discs, WeightedRandomAI};
 use othello_game::{Board, Colour, DefaultGame, Move, Pos, Score};
 
 // Helper to convert row, col to 0-63 representation or 0 for pass
@@ -13,6 +20,159 @@ fn move_to_u8(mov: Option<Move>) -> u8 {
     }
 }
 
+// Move representations (1-64) of the four corner squares: a1, h1, a8, h8
+const CORNERS: [u8; 4] = [1, 8, 57, 64];
+
+// (row, col) steps to each of a square's eight neighbours
+const DIRECTIONS: [(Pos, Pos); 8] = [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)];
+
+// Names of the DIRECTIONS, as the board is drawn, with row 1 at the top and column a on the left
+const DIRECTION_NAMES: [&str; 8] = ["up-left", "up", "up-right", "left", "right", "down-left", "down", "down-right"];
+
+// Strength that selects MobilityAI rather than a search depth or random play
+const MOBILITY_STRENGTH: i32 = -1;
+
+// Largest number of empty squares for which endgame positions are solved exactly
+const MAX_SOLVE_EMPTIES: Score = 8;
+
+// Number of discs (three quarters of the board) at which a player is taken to be dominating
+const DOMINANT_DISCS: Score = 48;
+
+// Named openings and their moves in standard notation, where the start position has White on d4,
+// so Black's first move is taken to be f5.  This board starts with Black on d4 instead, so it is the
+// mirror image of the standard one.
+const OPENINGS: [(&str, &str); 7] = [
+    ("Perpendicular opening", "f5d6"),
+    ("Diagonal opening", "f5f6"),
+    ("Parallel opening", "f5f4"),
+    ("Tiger", "f5d6c3d3c4"),
+    ("Cow", "f5d6c5"),
+    ("Rabbit", "f5f6e6f4e3"),
+    ("Heath", "f5f6e6f4g5"),
+];
+
+// Convert a sequence of squares in standard notation (e.g. "f5d6") to move representations (1-64),
+// mirroring them onto this board
+fn standard_moves(squares: &str) -> Vec<u8> {
+    squares.as_bytes()
+        .chunks(2)
+        .map(|square| {
+            let col = 7 - (square[0] - b'a');
+            let row = square[1] - b'1';
+            row * 8 + col + 1
+        })
+        .collect()
+}
+
+// Transform a move representation by one of the four symmetries of the start position: 0 is the
+// identity, 1 reflects in the a1-h8 diagonal, 2 rotates by 180 degrees and 3 reflects in the h1-a8
+// diagonal.  Each is its own inverse.  Passes are unchanged.
+fn transform_move(move_repr: u8, symmetry: usize) -> u8 {
+    if move_repr == 0 {
+        return 0;
+    }
+    let (row, col) = ((move_repr - 1) / 8, (move_repr - 1) % 8);
+    let (row, col) = match symmetry {
+        0 => (row, col),
+        1 => (col, row),
+        2 => (7 - row, 7 - col),
+        _ => (7 - col, 7 - row),
+    };
+    row * 8 + col + 1
+}
+
+// Value used for a pass in game tokens, where moves 1-64 are stored as themselves and 0 is padding
+const TOKEN_PASS: u8 = 65;
+
+const BASE64URL_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+// Encode bytes as unpadded base64url
+fn encode_base64url(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity((bytes.len() * 8).div_ceil(6));
+    let (mut buffer, mut bits) = (0u32, 0);
+    for &byte in bytes {
+        buffer = (buffer << 8) | byte as u32;
+        bits += 8;
+        while bits >= 6 {
+            bits -= 6;
+            encoded.push(BASE64URL_ALPHABET[((buffer >> bits) & 0x3f) as usize] as char);
+        }
+    }
+    if bits > 0 {
+        encoded.push(BASE64URL_ALPHABET[((buffer << (6 - bits)) & 0x3f) as usize] as char);
+    }
+    encoded
+}
+
+// Decode unpadded base64url, as produced by encode_base64url.  As that never ends with a lone
+// character or with set bits left over, anything else has been truncated or has junk added.
+fn decode_base64url(encoded: &str) -> PyResult<Vec<u8>> {
+    if encoded.len() % 4 == 1 {
+        return Err(PyValueError::new_err(format!("Invalid token length: {}", encoded.len())));
+    }
+    let mut bytes = Vec::with_capacity(encoded.len() * 6 / 8);
+    let (mut buffer, mut bits) = (0u32, 0);
+    for ch in encoded.bytes() {
+        let Some(value) = BASE64URL_ALPHABET.iter().position(|&c| c == ch) else {
+            return Err(PyValueError::new_err(format!("Invalid token character: {:?}", ch as char)));
+        };
+        buffer = (buffer << 6) | value as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+        }
+    }
+    if buffer & ((1 << bits) - 1) != 0 {
+        return Err(PyValueError::new_err("Invalid token: unused bits at the end are set"));
+    }
+    Ok(bytes)
+}
+
+// Pack move representations (0 for a pass, 1-64 for a stone) into 7 bits each, most significant
+// bit first, with passes stored as TOKEN_PASS
+fn pack_moves(moves: &[u8]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity((moves.len() * 7).div_ceil(8));
+    let (mut buffer, mut bits) = (0u32, 0);
+    for &move_repr in moves {
+        let value = if move_repr == 0 { TOKEN_PASS } else { move_repr };
+        buffer = (buffer << 7) | value as u32;
+        bits += 7;
+        while bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+        }
+    }
+    if bits > 0 {
+        bytes.push((buffer << (8 - bits)) as u8);
+    }
+    bytes
+}
+
+// Unpack moves packed by pack_moves.  Padding makes a final 0 when it's as long as a move, which
+// is dropped; it must be zero, and no longer than pack_moves makes it.
+fn unpack_moves(bytes: &[u8]) -> PyResult<Vec<u8>> {
+    let total_bits = bytes.len() * 8;
+    let bit = |pos: usize| (bytes[pos / 8] >> (7 - pos % 8)) & 1;
+    let mut moves = Vec::with_capacity(total_bits / 7);
+    for i in 0..total_bits / 7 {
+        let value = (0..7).fold(0u8, |value, j| (value << 1) | bit(i * 7 + j));
+        match value {
+            0 if i == total_bits / 7 - 1 => (),
+            1..=64 => moves.push(value),
+            TOKEN_PASS => moves.push(0),
+            _ => return Err(PyValueError::new_err(format!("Invalid move {} in token", value))),
+        }
+    }
+    if (moves.len() * 7).div_ceil(8) != bytes.len() {
+        return Err(PyValueError::new_err("Invalid token: too much padding after the last move"));
+    }
+    if (moves.len() * 7..total_bits).any(|pos| bit(pos) != 0) {
+        return Err(PyValueError::new_err("Invalid token: padding after the last move is set"));
+    }
+    Ok(moves)
+}
+
 // Helper to convert 0-64 representation back to Move or None for pass
 // Requires the current player's colour
 fn u8_to_move(move_repr: u8, player: Colour) -> PyResult<Option<Move>> {
@@ -23,7 +183,7 @@ fn u8_to_move(move_repr: u8, player: Colour) -> PyResult<Option<Move>> {
             let row = (index / 8) as Pos;
             let col = (index % 8) as Pos;
              // Basic bounds check, detailed validation happens later
-            if row >= 0 && row < 8 && col >= 0 && col < 8 {
+            if (0..8).contains(&row) && (0..8).contains(&col) {
                  Ok(Some(Move { player, row, col }))
             } else {
                  Err(PyValueError::new_err(format!("Invalid move number: {}", move_repr)))
@@ -33,12 +193,356 @@ fn u8_to_move(move_repr: u8, player: Colour) -> PyResult<Option<Move>> {
     }
 }
 
+// Python representation of a square's contents: 0 for empty, 1 for Black, 2 for White
+fn piece_to_u8(piece: Option<Colour>) -> u8 {
+    match piece {
+        None => 0,
+        Some(Colour::Black) => 1,
+        Some(Colour::White) => 2,
+    }
+}
+
+fn colour_to_u8(colour: Colour) -> u8 {
+    piece_to_u8(Some(colour))
+}
+
+// Helper to convert a Python player number (1 for Black, 2 for White) to a Colour
+fn u8_to_colour(player: u8) -> PyResult<Colour> {
+    match player {
+        1 => Ok(Colour::Black),
+        2 => Ok(Colour::White),
+        _ => Err(PyValueError::new_err(format!("Player must be 1 (Black) or 2 (White), got {}", player))),
+    }
+}
+
+fn colour_name(colour: Colour) -> &'static str {
+    match colour {
+        Colour::Black => "Black",
+        Colour::White => "White",
+    }
+}
+
+// The board as a list of 64 square contents (see piece_to_u8), in row order from a1
+fn board_to_vec<B: Board>(board: &B) -> Vec<u8> {
+    let mut board_repr: Vec<u8> = Vec::with_capacity(64);
+    for r in 0..8 {
+        for c in 0..8 {
+            board_repr.push(piece_to_u8(board.get(r, c)));
+        }
+    }
+    board_repr
+}
+
+// Indices (0-63) of the discs flipped by applying a valid move
+fn flipped_squares(game: &DefaultGame, mov: Move) -> Vec<u8> {
+    let after = game.apply(mov);
+    (0..64)
+        .filter(|i| {
+            let (r, c) = (i / 8, i % 8);
+            game.board.get(r, c).is_some() && game.board.get(r, c) != after.board.get(r, c)
+        })
+        .map(|i| i as u8)
+        .collect()
+}
+
+// The discs (0-63) a move would flip along each direction it flips any, as (direction, discs)
+// pairs in the order of DIRECTIONS, with each run of discs listed outwards from the move
+fn flips_by_direction(game: &DefaultGame, mov: Move) -> Vec<((Pos, Pos), Vec<u8>)> {
+    let on_board = |r: Pos, c: Pos| (0..8).contains(&r) && (0..8).contains(&c);
+    let mut flips = Vec::new();
+    for (dr, dc) in DIRECTIONS {
+        let mut run = Vec::new();
+        let (mut r, mut c) = (mov.row + dr, mov.col + dc);
+        while on_board(r, c) && game.board.get(r, c) == Some(mov.player.opponent()) {
+            run.push((r * 8 + c) as u8);
+            r += dr;
+            c += dc;
+        }
+        if !run.is_empty() && on_board(r, c) && game.board.get(r, c) == Some(mov.player) {
+            flips.push(((dr, dc), run));
+        }
+    }
+    flips
+}
+
+// Number of empty squares left on the board
+fn empty_count(game: &DefaultGame) -> Score {
+    let (black_score, white_score) = game.scores();
+    64 - black_score - white_score
+}
+
+// Name of a square in the usual notation, e.g. "c4" for row 3 (0-based), column 2
+fn square_name(row: Pos, col: Pos) -> String {
+    format!("{}{}", (b'a' + col as u8) as char, row + 1)
+}
+
+// Move representation (1-64) of a square named as by square_name (in either case), or 0 for "pass"
+fn parse_square(name: &str) -> PyResult<u8> {
+    let name = name.trim().to_ascii_lowercase();
+    if name == "pass" {
+        return Ok(0);
+    }
+    match name.as_bytes() {
+        &[col @ b'a'..=b'h', row @ b'1'..=b'8'] => Ok((row - b'1') * 8 + (col - b'a') + 1),
+        _ => Err(PyValueError::new_err(format!("Invalid square: {:?}", name))),
+    }
+}
+
+// Character used to show a square in text output, using B/W for clarity
+fn piece_char(piece: Option<Colour>) -> char {
+    match piece {
+        Some(Colour::Black) => 'B',
+        Some(Colour::White) => 'W',
+        None => '.',
+    }
+}
+
+// Occupancy of a line of squares as a string of '.', 'B' and 'W'
+fn line_string<B: Board>(board: &B, squares: impl Iterator<Item=(Pos, Pos)>) -> String {
+    squares.map(|(r, c)| piece_char(board.get(r, c))).collect()
+}
+
+// The colour occupying every square of a line (1 for Black, 2 for White), or 0 if the line is
+// mixed or has empty squares
+fn line_owner<B: Board>(board: &B, mut squares: impl Iterator<Item=(Pos, Pos)>) -> u8 {
+    let Some((r, c)) = squares.next() else { return 0 };
+    let first = board.get(r, c);
+    if first.is_some() && squares.all(|(r, c)| board.get(r, c) == first) { piece_to_u8(first) } else { 0 }
+}
+
+// Determine the AI's move for the current player without using dyn AI.
+// Strength is the AlphaBetaAI search depth; MOBILITY_STRENGTH uses MobilityAI, and any other
+// strength of 0 or less (or no strength) plays randomly, as RandomAI does.
+fn choose_ai_move(game: &DefaultGame, strength: Option<i32>) -> Option<Move> {
+    choose_ai_move_with_rng(game, strength, &mut rand::thread_rng())
+}
+
+// As choose_ai_move, but random play uses the given random number generator, so that games can
+// be reproduced from a seed
+fn choose_ai_move_with_rng<R: Rng>(game: &DefaultGame, strength: Option<i32>, rng: &mut R) -> Option<Move> {
+    match strength {
+        Some(s) if s > 0 => AlphaBetaAI::new(s as usize).choose_move(game),
+        Some(MOBILITY_STRENGTH) => MobilityAI {}.choose_move(game),
+        _ => game.valid_moves(game.next_turn).choose(rng).copied(),
+    }
+}
+
+// As choose_ai_move_with_rng, also returning the number of nodes searched (0 if not searching)
+fn choose_ai_move_with_nodes<R: Rng>(game: &DefaultGame, strength: i32, rng: &mut R) -> (Option<Move>, usize) {
+    if strength <= 0 {
+        return (choose_ai_move_with_rng(game, Some(strength), rng), 0);
+    }
+    let ai = AlphaBetaAI::new(strength as usize);
+    let mov = ai.choose_move(game);
+    (mov, ai.info().map_or(0, |info| info.nodes_searched.load(Ordering::Relaxed)))
+}
+
+// The value of a position to the player to move, as AlphaBetaAI scores their best move, allowing
+// for them having to pass, or the game being over (when it is their final disc difference)
+fn position_value(game: &DefaultGame, ai: &AlphaBetaAI) -> Score {
+    if let Some(best_score) = ai.score_moves(game).into_iter().map(|(_, score)| score).max() {
+        return best_score;
+    }
+    if game.valid_moves(game.next_turn.opponent()).is_empty() {
+        let (black_score, white_score) = game.scores();
+        return (black_score - white_score) * game.next_turn.sign();
+    }
+    let mut passed = game.clone();
+    passed.next_turn = game.next_turn.opponent();
+    -position_value(&passed, ai)
+}
+
+// Count the positions reached by every sequence of exactly `depth` plies from the given one,
+// where a forced pass counts as a ply and a finished game before then leads nowhere
+fn perft_count(game: &DefaultGame, depth: usize) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+    let moves = game.valid_moves(game.next_turn);
+    if !moves.is_empty() {
+        return moves.into_iter().map(|m| perft_count(&game.apply(m), depth - 1)).sum();
+    }
+    if game.valid_moves(game.next_turn.opponent()).is_empty() {
+        return 0;
+    }
+    let mut passed = game.clone();
+    passed.next_turn = game.next_turn.opponent();
+    perft_count(&passed, depth - 1)
+}
+
+// Play the game to the end with an AI of the given strength for each colour, passing whenever
+// the player to move has no legal moves
+fn play_to_end(mut game: DefaultGame, black_strength: i32, white_strength: i32, rng: &mut StdRng) -> DefaultGame {
+    loop {
+        let strength = if game.next_turn == Colour::Black { black_strength } else { white_strength };
+        if let Some(mov) = choose_ai_move_with_rng(&game, Some(strength), rng) {
+            game = game.apply(mov);
+        } else if game.valid_moves(game.next_turn.opponent()).is_empty() {
+            return game;
+        } else {
+            game.next_turn = game.next_turn.opponent();
+        }
+    }
+}
+
+// Validate a strength used for searching (rather than random play) as an AlphaBetaAI depth
+fn search_depth(strength: i32) -> PyResult<usize> {
+    if strength <= 0 {
+        return Err(PyValueError::new_err(format!("Search depth must be positive, got {}", strength)));
+    }
+    Ok(strength as usize)
+}
+
+// Play a random number of random moves from the start position, to get a position to search.
+// Positions where the side to move has no legal moves are discarded and another is generated.
+fn random_position(rng: &mut StdRng) -> DefaultGame {
+    loop {
+        let mut game = DefaultGame::new();
+        let plies = rng.gen_range(0..50);
+        for _ in 0..plies {
+            let moves: Vec<Move> = game.valid_moves(game.next_turn).into_iter().collect();
+            let Some(mov) = moves.choose(rng) else { break };
+            game = game.apply(*mov);
+        }
+        if game.valid_moves(game.next_turn).into_iter().next().is_some() {
+            return game;
+        }
+    }
+}
+
+// Statistics from an AI search, as returned by ai_move_full
+#[pyclass(name = "SearchStats")]
+#[derive(Clone)]
+struct PySearchStats {
+    #[pyo3(get)]
+    nodes: usize,
+    #[pyo3(get)]
+    depth: usize,
+    #[pyo3(get)]
+    time_seconds: f64,
+    // The move played, as returned by ai_move
+    #[pyo3(get)]
+    best_move: Option<u8>,
+    // The search's score for the move, or None for a pass
+    #[pyo3(get)]
+    best_score: Option<i64>,
+}
+
+#[pymethods]
+impl PySearchStats {
+    fn __repr__(&self) -> String {
+        let or_none = |value: Option<i64>| value.map_or("None".to_string(), |v| v.to_string());
+        format!("SearchStats(nodes={}, depth={}, time_seconds={}, best_move={}, best_score={})",
+                self.nodes, self.depth, self.time_seconds, or_none(self.best_move.map(i64::from)), or_none(self.best_score))
+    }
+}
+
 #[pyclass(name = "OthelloGame")]
 struct PyOthelloGame {
     game: DefaultGame,
     // Store moves as the u8 representation (0 for pass, 1-64 for place)
     // We could store the actual Move structs but u8 is simpler for the Python API
     move_history: Vec<u8>,
+    // Maximum search depth for ai_move, regardless of the strength asked for
+    max_depth_cap: Option<usize>,
+    // The player who has offered a draw that hasn't yet been accepted
+    draw_offered_by: Option<Colour>,
+    // Whether the players have agreed a draw, ending the game
+    draw_agreed: bool,
+    // Saved states (game, move_history, draw_offered_by, draw_agreed), indexed by checkpoint id
+    checkpoints: Vec<(DefaultGame, Vec<u8>, Option<Colour>, bool)>,
+}
+
+impl PyOthelloGame {
+    // Apply a valid stone placement and record it, returning its move representation.  Moving
+    // withdraws any draw offer, which has to be accepted before play goes on.
+    fn apply_move(&mut self, mov: Move) -> u8 {
+        self.game = self.game.apply(mov);
+        self.draw_offered_by = None;
+        let move_repr = move_to_u8(Some(mov));
+        self.move_history.push(move_repr);
+        move_repr
+    }
+
+    // Pass on behalf of the current player, who must have no legal moves, and record it; like a
+    // stone placement, this withdraws any draw offer
+    fn apply_pass(&mut self) {
+        self.game.next_turn = self.game.next_turn.opponent();
+        self.draw_offered_by = None;
+        self.move_history.push(0);
+    }
+
+    // Whether the current player can't place a stone, because they have to pass or a draw has been
+    // agreed; ai_move handles both, so its variants leave these cases to it
+    fn cannot_place(&self) -> bool {
+        self.draw_agreed || self.game.valid_moves(self.game.next_turn).is_empty()
+    }
+
+    // Clamp a strength passed to ai_move to the maximum depth cap, if one has been set.  ai_move
+    // and its variants go through this, so a cap of 0 makes them play randomly.
+    fn capped_strength(&self, strength: i32) -> i32 {
+        match self.max_depth_cap {
+            Some(cap) if strength > 0 => strength.min(cap.min(i32::MAX as usize) as i32),
+            _ => strength,
+        }
+    }
+
+    // The search depth for a strength, which must be positive, limited by the maximum depth cap if
+    // one has been set, though never to less than one ply.  The methods that always search (rather
+    // than playing like ai_move) go through this.
+    fn capped_search_depth(&self, strength: i32) -> PyResult<usize> {
+        let depth = search_depth(strength)?;
+        Ok(self.max_depth_cap.map_or(depth, |cap| depth.min(cap.max(1))))
+    }
+
+    // Choose a move for the player to move in the given game as choose_ai_move does, with the
+    // strength limited by the maximum depth cap
+    fn choose_ai_move(&self, game: &DefaultGame, strength: Option<i32>) -> Option<Move> {
+        choose_ai_move(game, strength.map(|s| self.capped_strength(s)))
+    }
+
+    // Every position in the game so far, from the start position to the current one, by replaying
+    // move_history; position i is the one in which move i was played
+    fn replay(&self) -> Vec<DefaultGame> {
+        let mut positions = vec![DefaultGame::new()];
+        for &move_repr in &self.move_history {
+            let game = positions.last().expect("at least the start position");
+            let next = match u8_to_move(move_repr, game.next_turn) {
+                Ok(Some(mov)) => game.apply(mov),
+                // History only contains validated moves, so anything else is a pass
+                _ => {
+                    let mut passed = game.clone();
+                    passed.next_turn = game.next_turn.opponent();
+                    passed
+                }
+            };
+            positions.push(next);
+        }
+        positions
+    }
+
+    // A copy of this game for trying out moves on, without the checkpoints, which belong to this
+    // game alone (and would be costly to copy for every hypothetical move)
+    fn scratch_copy(&self) -> PyOthelloGame {
+        PyOthelloGame {
+            game: self.game.clone(),
+            move_history: self.move_history.clone(),
+            max_depth_cap: self.max_depth_cap,
+            draw_offered_by: self.draw_offered_by,
+            draw_agreed: self.draw_agreed,
+            checkpoints: Vec::new(),
+        }
+    }
+
+    // A copy of this game with the given move (0 for a pass, 1-64 for a stone) applied, checking
+    // it in the same way as add_stone, so that hypothetical moves can be examined
+    fn after_move(&self, move_repr: u8) -> PyResult<PyOthelloGame> {
+        let mut game = self.scratch_copy();
+        if !game.add_stone(move_repr)? {
+            return Err(PyValueError::new_err(format!("Illegal move: {}", move_repr)));
+        }
+        Ok(game)
+    }
 }
 
 #[pymethods]
@@ -48,7 +552,67 @@ impl PyOthelloGame {
         PyOthelloGame {
             game: DefaultGame::new(),
             move_history: Vec::new(),
+            max_depth_cap: None,
+            draw_offered_by: None,
+            draw_agreed: false,
+            checkpoints: Vec::new(),
+        }
+    }
+
+    /// Create a game by replaying a sequence of moves (0 for a pass, 1-64 for a stone), inserting a
+    /// pass wherever the player to move has no legal moves, so that passes can be left out.
+    /// Raises ValueError, giving the index of the offending move, if any move is illegal.
+    #[staticmethod]
+    fn from_moves(moves: Vec<u8>) -> PyResult<PyOthelloGame> {
+        let mut game = PyOthelloGame::new();
+        for (i, move_repr) in moves.into_iter().enumerate() {
+            if move_repr != 0 && game.game.valid_moves(game.game.next_turn).is_empty() {
+                game.add_stone(0)?;
+            }
+            if !game.add_stone(move_repr)? {
+                return Err(PyValueError::new_err(format!("Illegal move {} at index {}", move_repr, i)));
+            }
+        }
+        Ok(game)
+    }
+
+    /// Create a game by replaying a sequence of tokens in which -1 is a pass and 1-64 place a
+    /// stone, for transcripts that don't use 0 for a pass.
+    /// Raises ValueError, giving the index of the offending token, if any token is invalid or illegal.
+    #[staticmethod]
+    fn from_moves_with_passes(tokens: Vec<i32>) -> PyResult<PyOthelloGame> {
+        let mut game = PyOthelloGame::new();
+        for (i, token) in tokens.into_iter().enumerate() {
+            let move_repr = match token {
+                -1 => 0,
+                1..=64 => token as u8,
+                _ => return Err(PyValueError::new_err(format!("Invalid token {} at index {}", token, i))),
+            };
+            if !game.add_stone(move_repr)? {
+                return Err(PyValueError::new_err(format!("Illegal move {} at index {}", token, i)));
+            }
+        }
+        Ok(game)
+    }
+
+    /// Encode the moves made so far as a compact URL-safe token, which from_token turns back into
+    /// this game.
+    fn to_token(&self) -> String {
+        encode_base64url(&pack_moves(&self.move_history))
+    }
+
+    /// Create a game by replaying the moves in a token made by to_token.
+    /// Raises ValueError if the token is malformed, including one that to_token could not have made
+    /// (e.g. cut short or with characters added), or contains an illegal move.
+    #[staticmethod]
+    fn from_token(token: &str) -> PyResult<PyOthelloGame> {
+        let mut game = PyOthelloGame::new();
+        for (i, move_repr) in unpack_moves(&decode_base64url(token)?)?.into_iter().enumerate() {
+            if !game.add_stone(move_repr)? {
+                return Err(PyValueError::new_err(format!("Illegal move {} at index {} in token", move_repr, i)));
+            }
         }
+        Ok(game)
     }
 
     /// List all moves made so far. 0 represents a pass, 1-64 represent placing a stone.
@@ -58,18 +622,173 @@ impl PyOthelloGame {
         Ok(PyList::new_bound(py, &self.move_history).into())
     }
 
+    /// List the stone placements (1-64) made so far, leaving out passes.  As the passes left out
+    /// were all forced, from_moves turns this back into the same game, unless it ended with a pass:
+    /// from_moves stops after the last placement, leaving the player who passed still to move.
+    fn placement_history(&self, py: Python<'_>) -> PyResult<Py<PyList>> {
+        let placements: Vec<u8> = self.move_history.iter().copied().filter(|&m| m != 0).collect();
+        Ok(PyList::new_bound(py, placements).into())
+    }
+
+    /// List the legal moves (1-64) for the given player (1 for Black, 2 for White), whether or not
+    /// it is their turn.
+    fn valid_moves_for(&self, player: u8, py: Python<'_>) -> PyResult<Py<PyList>> {
+        let colour = u8_to_colour(player)?;
+        let moves: Vec<u8> = self.game.valid_moves(colour)
+            .into_iter()
+            .map(|m| move_to_u8(Some(m)))
+            .collect();
+        Ok(PyList::new_bound(py, &moves).into())
+    }
+
+    /// Count the legal stone placements for the current player, without building a list of them.
+    fn legal_move_count(&self) -> usize {
+        self.game.valid_moves(self.game.next_turn).len()
+    }
+
+    /// List the legal moves for the current player that place a stone on a corner (a1/h1/a8/h8).
+    /// An empty list means no corner is immediately available.
+    fn corner_moves(&self, py: Python<'_>) -> PyResult<Py<PyList>> {
+        let corner_moves: Vec<u8> = self.game.valid_moves(self.game.next_turn)
+            .into_iter()
+            .map(|m| move_to_u8(Some(m)))
+            .filter(|repr| CORNERS.contains(repr))
+            .collect();
+        Ok(PyList::new_bound(py, &corner_moves).into())
+    }
+
+    /// Count the corners each player could legally play on now, whether or not it is their turn,
+    /// as (black_corner_moves, white_corner_moves).
+    fn corner_availability(&self, py: Python<'_>) -> PyResult<Py<PyTuple>> {
+        let corner_count = |colour: Colour| self.game.valid_moves(colour)
+            .into_iter()
+            .filter(|&m| CORNERS.contains(&move_to_u8(Some(m))))
+            .count();
+        let counts = [corner_count(Colour::Black), corner_count(Colour::White)];
+        Ok(PyTuple::new_bound(py, &[counts[0].into_py(py), counts[1].into_py(py)]).into())
+    }
+
+    /// Group the legal moves (1-64) for the current player by the square they place a stone on,
+    /// as a dict mapping "corner", "edge" (the rest of the outer ring) and "inner" to lists of moves.
+    fn move_categories(&self, py: Python<'_>) -> PyResult<Py<PyDict>> {
+        let (mut corner, mut edge, mut inner): (Vec<u8>, Vec<u8>, Vec<u8>) = (vec![], vec![], vec![]);
+        for mov in self.game.valid_moves(self.game.next_turn) {
+            let move_repr = move_to_u8(Some(mov));
+            if CORNERS.contains(&move_repr) {
+                corner.push(move_repr);
+            } else if mov.row == 0 || mov.row == 7 || mov.col == 0 || mov.col == 7 {
+                edge.push(move_repr);
+            } else {
+                inner.push(move_repr);
+            }
+        }
+
+        let dict = PyDict::new_bound(py);
+        dict.set_item("corner", corner)?;
+        dict.set_item("edge", edge)?;
+        dict.set_item("inner", inner)?;
+        Ok(dict.into())
+    }
+
+    /// List the legal moves for the current player as (move, weight) tuples, where the weight is
+    /// that of the square the move places a stone on (see positional_weights), best square first.
+    /// This is a cheap static ranking, without any search.
+    fn moves_by_positional_value(&self, py: Python<'_>) -> PyResult<Py<PyList>> {
+        let mut moves: Vec<(u8, Score)> = self.game.valid_moves(self.game.next_turn)
+            .into_iter()
+            .map(|m| (move_to_u8(Some(m)), POSITIONAL_WEIGHTS[m.row as usize][m.col as usize]))
+            .collect();
+        moves.sort_by_key(|&(_, weight)| std::cmp::Reverse(weight));
+        Ok(PyList::new_bound(py, moves).into())
+    }
+
+    /// List the moves made so far as (player, move) tuples, where player is 1 for Black or 2 for
+    /// White, and move is 0 for a pass or 1-64 for a stone.  Players don't strictly alternate, as
+    /// each pass is attributed to the player who had to pass.
+    fn annotated_history(&self, py: Python<'_>) -> PyResult<Py<PyList>> {
+        let moves: Vec<(u8, u8)> = self.replay().iter()
+            .zip(&self.move_history)
+            .map(|(game, &move_repr)| (colour_to_u8(game.next_turn), move_repr))
+            .collect();
+        Ok(PyList::new_bound(py, moves).into())
+    }
+
+    /// List the stone placements made so far as dicts with keys "square" (the move, 1-64) and
+    /// "flips" (the indices, 0-63, of the discs it flipped), leaving out passes.  Applying each in
+    /// turn to the start position rebuilds the board without sending whole boards.
+    fn move_log(&self, py: Python<'_>) -> PyResult<Py<PyList>> {
+        let list = PyList::empty_bound(py);
+        for (game, &move_repr) in self.replay().iter().zip(&self.move_history) {
+            let Some(mov) = u8_to_move(move_repr, game.next_turn)? else { continue };
+            let dict = PyDict::new_bound(py);
+            dict.set_item("square", move_repr)?;
+            dict.set_item("flips", flipped_squares(game, mov))?;
+            list.append(dict)?;
+        }
+        Ok(list.into())
+    }
+
+    /// Get the name of the opening played, the longest of the known named openings that the moves so
+    /// far begin with (allowing for the game being a reflection or rotation of it), e.g. "Tiger".
+    /// Returns None if the game doesn't start with a known opening.
+    fn opening_name(&self) -> Option<String> {
+        let first_move = *self.move_history.first()?;
+        let opening_first_move = standard_moves("f5")[0];
+        let symmetry = (0..4).find(|&s| transform_move(first_move, s) == opening_first_move)?;
+        let moves: Vec<u8> = self.move_history.iter().map(|&m| transform_move(m, symmetry)).collect();
+
+        OPENINGS.iter()
+            .map(|(name, squares)| (name, standard_moves(squares)))
+            .filter(|(_, opening_moves)| moves.starts_with(opening_moves))
+            .max_by_key(|(_, opening_moves)| opening_moves.len())
+            .map(|(name, _)| name.to_string())
+    }
+
+    /// Get the number of the move about to be played, counting from 1 and including passes.
+    fn ply_number(&self) -> usize {
+        self.move_history.len() + 1
+    }
+
+    /// Check if the most recent move was a pass (false if no moves have been made).
+    fn last_was_pass(&self) -> bool {
+        self.move_history.last() == Some(&0)
+    }
+
+    /// Get the number of discs flipped by the most recent move, or None if it was a pass or no
+    /// moves have been made.
+    fn last_flip_count(&self) -> PyResult<Option<u32>> {
+        let Some(&last_move) = self.move_history.last() else { return Ok(None) };
+        let positions = self.replay();
+        let before = &positions[positions.len() - 2];
+        Ok(u8_to_move(last_move, before.next_turn)?.map(|mov| flipped_squares(before, mov).len() as u32))
+    }
+
+    /// Check whether the current player gets to move again because their opponent had to pass,
+    /// i.e. the most recent move was a pass and the game isn't over.
+    fn has_extra_turn(&self) -> PyResult<bool> {
+        Ok(self.last_was_pass() && !self.is_game_over()?)
+    }
+
+    /// Count the passes at the end of the move history.  Two passes in a row end the game.
+    fn consecutive_passes(&self) -> usize {
+        self.move_history.iter().rev().take_while(|&&m| m == 0).count()
+    }
+
     /// Add a stone placement (1-64) or a pass (0).
     /// Returns true if the move was valid and applied, false otherwise.
     fn add_stone(&mut self, move_repr: u8) -> PyResult<bool> {
         let current_player = self.game.next_turn;
+        // No moves can be made once a draw has been agreed
+        if self.draw_agreed {
+            return Ok(false);
+        }
         let valid_moves: Vec<Move> = self.game.valid_moves(current_player).into_iter().collect();
 
         match u8_to_move(move_repr, current_player)? {
             Some(potential_move) => {
                 // Check if the proposed move is in the list of valid moves
                 if valid_moves.contains(&potential_move) {
-                    self.game = self.game.apply(potential_move);
-                    self.move_history.push(move_repr);
+                    self.apply_move(potential_move);
                     Ok(true)
                 } else {
                     // Illegal placement
@@ -80,13 +799,12 @@ impl PyOthelloGame {
                 // Pass is only valid if there are no other moves
                 if valid_moves.is_empty() {
                     // Apply the "pass" by switching the turn without changing the board
-                    self.game.next_turn = self.game.next_turn.opponent();
+                    self.apply_pass();
                     // Check if the *new* player also has no moves (game over condition)
                      if self.game.valid_moves(self.game.next_turn).into_iter().next().is_none() {
                         // Game is over, turn doesn't advance further in a real pass scenario
                         // but we keep the opponent's colour as next_turn to signify game end
                     }
-                    self.move_history.push(0);
                     Ok(true)
                 } else {
                     // Cannot pass if other moves are available
@@ -96,54 +814,111 @@ impl PyOthelloGame {
         }
     }
 
+    /// Add a stone placement (1-64) or a pass (0) as add_stone does, also checking whether it is the
+    /// move the AI would have chosen at the given strength (as for ai_move).  Returns
+    /// (applied, was_best), which is (false, false) if the move is illegal.
+    fn add_stone_scored(&mut self, move_repr: u8, strength: i32) -> PyResult<(bool, bool)> {
+        let best_move = move_to_u8(self.choose_ai_move(&self.game, Some(strength)));
+        let applied = self.add_stone(move_repr)?;
+        Ok((applied, applied && move_repr == best_move))
+    }
+
+    /// Add a stone at the given row and column (each 0-7), as add_stone does.
+    /// Returns true if the move was valid and applied, false otherwise.  Raises ValueError if the row
+    /// or column is out of range.
+    fn add_stone_rc(&mut self, row: u8, col: u8) -> PyResult<bool> {
+        if row >= 8 || col >= 8 {
+            return Err(PyValueError::new_err(format!("Row and column must be between 0 and 7, got ({}, {})", row, col)));
+        }
+        self.add_stone(row * 8 + col + 1)
+    }
+
+    /// Apply a stone placement (1-64) or a pass (0) and return this game, so that calls can be
+    /// chained, e.g. `game.with_move(21).with_move(20)`.
+    /// Raises ValueError if the move is not legal, rather than returning false like add_stone.
+    fn with_move(slf: &Bound<'_, Self>, move_repr: u8) -> PyResult<Py<Self>> {
+        if !slf.borrow_mut().add_stone(move_repr)? {
+            return Err(PyValueError::new_err(format!("Illegal move: {}", move_repr)));
+        }
+        Ok(slf.clone().unbind())
+    }
+
+    /// Turn a move given either as a number (0 for a pass, 1-64 for a stone) or as a square name
+    /// such as "e3" (or "pass") into its number, checking that it is legal for the current player.
+    /// Raises ValueError if the move can't be understood or is not legal.
+    fn normalize_move(&self, token: &Bound<'_, PyAny>) -> PyResult<u8> {
+        let move_repr = if let Ok(name) = token.extract::<String>() {
+            parse_square(&name)?
+        } else {
+            let number: i64 = token.extract()
+                .map_err(|_| PyValueError::new_err(format!("Move must be a number or a square name, got {}", token)))?;
+            u8::try_from(number).ok().filter(|&m| m <= 64)
+                .ok_or_else(|| PyValueError::new_err(format!("Move must be between 0 and 64, got {}", number)))?
+        };
+        self.after_move(move_repr)?;
+        Ok(move_repr)
+    }
+
+    /// Save the current state of the game, returning an id that restore can later return to.
+    fn checkpoint(&mut self) -> usize {
+        self.checkpoints.push((self.game.clone(), self.move_history.clone(), self.draw_offered_by, self.draw_agreed));
+        self.checkpoints.len() - 1
+    }
+
+    /// Return the game to the state it was in when checkpoint returned the given id.  Checkpoints
+    /// are kept, so the same one can be restored any number of times.
+    /// Raises ValueError if there is no checkpoint with that id.
+    fn restore(&mut self, id: usize) -> PyResult<()> {
+        let (game, move_history, draw_offered_by, draw_agreed) = self.checkpoints.get(id)
+            .ok_or_else(|| PyValueError::new_err(format!("No checkpoint with id {}", id)))?
+            .clone();
+        (self.game, self.move_history, self.draw_offered_by, self.draw_agreed) =
+            (game, move_history, draw_offered_by, draw_agreed);
+        Ok(())
+    }
+
+    /// Cap the search depth used by ai_move and every other method that searches, so that any
+    /// higher strength or depth is treated as the cap.  With a cap of 0, positive strengths play
+    /// randomly in ai_move and its variants, while methods that need a search go one ply deep.
+    /// There is no cap to begin with, and passing None removes it.
+    fn set_max_depth_cap(&mut self, cap: Option<usize>) {
+        self.max_depth_cap = cap;
+    }
 
     /// Have the AI determine the next move, apply it, and return the move representation (0-64).
     /// Strength corresponds to the search depth for AlphaBetaAI (e.g., 1-5).
-    /// If strength is 0 or less, RandomAI is used. Returns None if no move is possible for AI (incl. game over).
+    /// A strength of -1 uses MobilityAI, which minimises the opponent's replies.
+    /// If strength is otherwise 0 or less, moves are chosen randomly. Returns None if no move is possible for AI (incl. game over).
     fn ai_move(&mut self, strength: Option<i32>) -> PyResult<Option<u8>> {
+        // An agreed draw has ended the game
+        if self.draw_agreed {
+            return Ok(None);
+        }
         let current_player = self.game.next_turn;
         let valid_moves: Vec<Move> = self.game.valid_moves(current_player).into_iter().collect();
 
         if valid_moves.is_empty() {
             // Current player must pass
-            self.game.next_turn = current_player.opponent();
+            self.apply_pass(); // Record the pass
             // Check if opponent also has no moves -> game over
             if self.game.valid_moves(self.game.next_turn).into_iter().next().is_none() {
                 // Game is over, no move made by AI
-                self.move_history.push(0); // Record the pass
                 return Ok(None); // No AI move applied
             } else {
                 // Opponent *can* move, so the pass was successful
-                self.move_history.push(0); // Record the pass
                 return Ok(Some(0)); // Return 0 to signify the pass
             }
         }
 
         // If we reach here, there are valid moves for the current player
-        // Determine the move without using dyn AI
-        let chosen_move_struct = if let Some(s) = strength {
-            if s > 0 {
-                let ai = AlphaBetaAI { max_depth: s as usize };
-                ai.choose_move(&self.game)
-            } else {
-                let ai = RandomAI {};
-                ai.choose_move(&self.game)
-            }
-        } else {
-            // Default to RandomAI if strength is not provided
-            let ai = RandomAI {};
-            ai.choose_move(&self.game)
-        };
+        let chosen_move_struct = self.choose_ai_move(&self.game, strength);
 
         if let Some(mov) = chosen_move_struct {
             // Ensure the AI's chosen move is actually valid (should always be if AI is correct)
             // Note: valid_moves check might be redundant if AI guarantees valid moves,
             // but keep for safety.
             if valid_moves.contains(&mov) {
-                self.game = self.game.apply(mov);
-                let move_repr = move_to_u8(Some(mov));
-                self.move_history.push(move_repr);
-                Ok(Some(move_repr))
+                Ok(Some(self.apply_move(mov)))
             } else {
                 // This case indicates an internal error or AI bug
                  Err(PyValueError::new_err(format!("AI chose an invalid move: {:?}", mov)))
@@ -155,32 +930,495 @@ impl PyOthelloGame {
         }
     }
 
+    /// Like ai_move, but calls should_cancel (a callable taking no arguments) periodically while
+    /// searching.  If it returns true the search stops, and the best move found so far is applied
+    /// and returned, or None (leaving the game unchanged) if no move had been completely searched.
+    /// Strengths of 0 or less don't search, so behave exactly like ai_move.
+    fn ai_move_cancellable(&mut self, py: Python<'_>, strength: i32, should_cancel: PyObject) -> PyResult<Option<u8>> {
+        let strength = self.capped_strength(strength);
+        if strength <= 0 || self.cannot_place() {
+            return self.ai_move(Some(strength));
+        }
+
+        // An error from the callback cancels the search and is then raised
+        let callback_error = Cell::new(None);
+        let ai = AlphaBetaAI::new(strength as usize);
+        let mov = ai.choose_move_cancellable(&self.game, || {
+            match should_cancel.call0(py).and_then(|result| result.bind(py).is_truthy()) {
+                Ok(cancel) => cancel,
+                Err(err) => {
+                    callback_error.set(Some(err));
+                    true
+                }
+            }
+        });
+        if let Some(err) = callback_error.into_inner() {
+            return Err(err);
+        }
+
+        Ok(mov.map(|m| self.apply_move(m)))
+    }
+
+    /// Like ai_move with a search depth (strength), but also returns the effective branching factor
+    /// of the search, nodes ** (1 / depth), as (move, branching factor).  Better move ordering prunes
+    /// more and so lowers it.  The branching factor is 0.0 if the current player had to pass.
+    /// Raises ValueError if strength is not positive.
+    fn ai_move_branching(&mut self, strength: i32) -> PyResult<(Option<u8>, f64)> {
+        let depth = self.capped_search_depth(strength)?;
+        if self.cannot_place() {
+            return Ok((self.ai_move(None)?, 0.0));
+        }
+
+        let (mov, nodes) = choose_ai_move_with_nodes(&self.game, depth as i32, &mut rand::thread_rng());
+        let mov = mov.ok_or_else(|| PyValueError::new_err("AI failed to choose a move despite available options"))?;
+        Ok((Some(self.apply_move(mov)), (nodes as f64).powf(1.0 / depth as f64)))
+    }
+
+    /// Like ai_move with a search depth (strength), but only plays the AI's move if the search
+    /// scores it at least min_score for the current player; otherwise (or if the game is over)
+    /// returns None and leaves the game unchanged, e.g. so that the AI can resign a lost game.
+    /// A forced pass is scored by the position it leads to.  Raises ValueError if strength is not
+    /// positive.
+    fn ai_move_if_above(&mut self, strength: i32, min_score: i64) -> PyResult<Option<u8>> {
+        let ai = AlphaBetaAI::new(self.capped_search_depth(strength)?);
+        if self.is_game_over()? {
+            return Ok(None);
+        }
+        // max_by_key picks the last of equal scores, which is the move choose_move picks
+        match ai.score_moves(&self.game).into_iter().max_by_key(|(_, score)| *score) {
+            Some((_, score)) if (score as i64) < min_score => Ok(None),
+            Some((mov, _)) => Ok(Some(self.apply_move(mov))),
+            // The game isn't over, so the current player must pass
+            None if (position_value(&self.game, &ai) as i64) < min_score => Ok(None),
+            None => self.ai_move(None),
+        }
+    }
+
+    /// Like ai_move with a search depth (strength), but returns a SearchStats with the number of
+    /// nodes searched, the depth, the time taken in seconds, the move played (best_move) and its
+    /// score (best_score).  If the current player had to pass, no search is made, so nodes and
+    /// depth are 0 and best_score is None.  Raises ValueError if strength is not positive.
+    fn ai_move_full(&mut self, strength: i32) -> PyResult<PySearchStats> {
+        let depth = self.capped_search_depth(strength)?;
+        let start = Instant::now();
+        let ai = AlphaBetaAI::new(depth);
+        // max_by_key picks the last of equal scores, which is the move choose_move picks
+        let best = if self.cannot_place() { None } else {
+            ai.score_moves(&self.game).into_iter().max_by_key(|(_, score)| *score)
+        };
+
+        let (best_move, best_score, depth) = match best {
+            Some((mov, score)) => (Some(self.apply_move(mov)), Some(score as i64), depth),
+            None => (self.ai_move(None)?, None, 0),
+        };
+        Ok(PySearchStats {
+            nodes: ai.info().map_or(0, |info| info.nodes_searched.load(Ordering::Relaxed)),
+            depth,
+            time_seconds: start.elapsed().as_secs_f64(),
+            best_move,
+            best_score,
+        })
+    }
+
+    /// Like ai_move, but searches for as long as the given number of seconds allows, by iterative
+    /// deepening: searching to depth 1, then 2 and so on until time runs out, and playing the move
+    /// from the deepest search completed.  Returns (move, depth), where depth is the deepest search
+    /// completed.  The search to depth 1 is always completed, however short the limit.  Searches
+    /// never go deeper than the maximum depth cap, if set, and with a cap of 0 the move is random,
+    /// as for ai_move.  Depth is 0 when no search was made, including when the current player had
+    /// to pass.  A limit too long to keep time for (such as infinity) searches with no deadline at
+    /// all.  Raises ValueError if seconds is not positive.
+    fn ai_move_timed(&mut self, seconds: f64) -> PyResult<(Option<u8>, u32)> {
+        if seconds.is_nan() || seconds <= 0.0 {
+            return Err(PyValueError::new_err(format!("Time limit must be positive, got {}", seconds)));
+        }
+        // The deepest ai_move would search at any strength
+        let max_strength = self.capped_strength(i32::MAX);
+        if max_strength <= 0 || self.cannot_place() {
+            return Ok((self.ai_move(Some(max_strength))?, 0));
+        }
+
+        // A time limit too long to represent means there is no deadline
+        let deadline = std::time::Duration::try_from_secs_f64(seconds).ok()
+            .and_then(|limit| Instant::now().checked_add(limit));
+        // Searching deeper than the number of empty squares can't see any further
+        let max_depth = (max_strength as usize).min(empty_count(&self.game) as usize);
+        let (mut best_move, mut completed_depth) = (None, 0);
+        for depth in 1..=max_depth {
+            // Only searches after the first can time out, so there is always a move to play
+            let timed_out = Cell::new(false);
+            let mov = AlphaBetaAI::new(depth).choose_move_cancellable(&self.game, || {
+                timed_out.set(depth > 1 && deadline.is_some_and(|deadline| Instant::now() >= deadline));
+                timed_out.get()
+            });
+            if timed_out.get() {
+                break;
+            }
+            (best_move, completed_depth) = (mov, depth as u32);
+        }
+
+        let mov = best_move.expect("the search to depth 1 completes");
+        Ok((Some(self.apply_move(mov)), completed_depth))
+    }
+
+    /// Like ai_move, but plays randomly, favouring moves that flip more discs: each move is chosen
+    /// with probability in proportion to exp(flips / temperature).  A high temperature plays almost
+    /// uniformly at random, and a temperature near zero always flips as many discs as possible.
+    /// Random play is seeded by seed, if given.
+    fn ai_move_weighted(&mut self, temperature: f64, seed: Option<u64>) -> PyResult<Option<u8>> {
+        if self.cannot_place() {
+            return self.ai_move(None);
+        }
+
+        let ai = WeightedRandomAI { temperature };
+        let mov = match seed {
+            Some(seed) => ai.choose_move_with_rng(&self.game, &mut StdRng::seed_from_u64(seed)),
+            None => ai.choose_move(&self.game),
+        };
+        Ok(mov.map(|m| self.apply_move(m)))
+    }
+
+    /// Play a legal move chosen uniformly at random (or pass if there are none), as ai_move does at
+    /// strength 0 but without going through an AI, returning the move played.  Random play is seeded
+    /// by seed, if given.
+    fn random_move(&mut self, seed: Option<u64>) -> PyResult<Option<u8>> {
+        if self.cannot_place() {
+            return self.ai_move(None);
+        }
+        let moves = self.game.valid_moves(self.game.next_turn);
+        let mov = match seed {
+            Some(seed) => moves.choose(&mut StdRng::seed_from_u64(seed)),
+            None => moves.choose(&mut rand::thread_rng()),
+        };
+        Ok(Some(self.apply_move(*mov.expect("some legal moves"))))
+    }
+
+    /// Like ai_move, but uses Monte Carlo Tree Search (MctsAI) with the given number of iterations,
+    /// each playing one random game out to the end.  Random play is seeded by seed, if given, in
+    /// which case the same move is chosen every time.  Raises ValueError if iterations is 0.
+    fn ai_move_mcts(&mut self, iterations: usize, seed: Option<u64>) -> PyResult<Option<u8>> {
+        if iterations == 0 {
+            return Err(PyValueError::new_err("iterations must be positive"));
+        }
+        if self.cannot_place() {
+            return self.ai_move(None);
+        }
+        let mov = MctsAI::new(iterations, seed).choose_move(&self.game);
+        Ok(mov.map(|m| self.apply_move(m)))
+    }
+
+    /// Like ai_move, but also returns a short human-readable reason for the move, such as
+    /// "captured corner h8", "forced move" or "maximized mobility".  The reason is a simple
+    /// after-the-fact guess at what makes the move stand out, not how the AI actually chose it.
+    fn ai_move_explained(&mut self, strength: i32) -> PyResult<(Option<u8>, String)> {
+        let before = self.game.clone();
+        let result = self.ai_move(Some(strength))?;
+        let Some(mov) = result.and_then(|repr| u8_to_move(repr, before.next_turn).ok().flatten()) else {
+            let reason = if result.is_some() { "forced pass" } else { "game over" };
+            return Ok((result, reason.to_string()));
+        };
+
+        let moves: Vec<Move> = before.valid_moves(before.next_turn).into_iter().collect();
+        let mobility = |m: &Move| evaluate_mobility(&before.apply(*m), m.player);
+        let flips = |m: &Move| flipped_squares(&before, *m).len();
+        let reason = if moves.len() == 1 {
+            "forced move".to_string()
+        } else if CORNERS.contains(&move_to_u8(Some(mov))) {
+            format!("captured corner {}", square_name(mov.row, mov.col))
+        } else if moves.iter().all(|m| mobility(m) <= mobility(&mov)) {
+            "maximized mobility".to_string()
+        } else if moves.iter().all(|m| flips(m) <= flips(&mov)) {
+            "maximized flips".to_string()
+        } else {
+            "best search score".to_string()
+        };
+        Ok((result, reason))
+    }
+
+    /// Play the game out to the end with the AI at the given strength (as for ai_move) moving for
+    /// both players, passing when necessary.  Random play is seeded by seed, if given.
+    /// Returns a list with a (move, elapsed_seconds, nodes) tuple for each ply, where move is 0
+    /// for a pass and nodes is the number of positions searched (0 when not searching).
+    fn autoplay_to_end(&mut self, strength: i32, seed: Option<u64>, py: Python<'_>) -> PyResult<Py<PyList>> {
+        let strength = self.capped_strength(strength);
+        let mut rng = match seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+
+        let mut plies: Vec<(u8, f64, usize)> = Vec::new();
+        while !self.is_game_over()? {
+            let start = Instant::now();
+            let (mov, nodes) = choose_ai_move_with_nodes(&self.game, strength, &mut rng);
+            let move_repr = match mov {
+                Some(mov) => self.apply_move(mov),
+                // The game isn't over, so no move means the current player must pass
+                None => {
+                    self.apply_pass();
+                    0
+                }
+            };
+            plies.push((move_repr, start.elapsed().as_secs_f64(), nodes));
+        }
+        Ok(PyList::new_bound(py, plies).into())
+    }
+
+    /// Play a match between a Python callback and the AI, until the game is over or max_plies plies
+    /// (including passes) have been played.  The callback plays the colour to move now: it is called
+    /// with the board (as the board getter) and returns its move, as a number or a square name (see
+    /// normalize_move); it mustn't use this game, which is busy playing.  The AI plays the other
+    /// colour at the given strength, as ai_move does.  Forced passes are made without calling the
+    /// callback.  Returns the moves so far (as list_moves).
+    /// Raises ValueError if the callback's move is not legal.
+    fn play_vs_callback(&mut self, ai_strength: i32, human_move: PyObject, max_plies: usize, py: Python<'_>) -> PyResult<Py<PyList>> {
+        let human = self.game.next_turn;
+        for _ in 0..max_plies {
+            if self.is_game_over()? {
+                break;
+            }
+            if self.game.next_turn != human {
+                self.ai_move(Some(ai_strength))?;
+            } else if self.game.valid_moves(human).is_empty() {
+                self.add_stone(0)?;
+            } else {
+                let token = human_move.call1(py, (self.board(py)?,))?;
+                let move_repr = self.normalize_move(token.bind(py))?;
+                self.add_stone(move_repr)?;
+            }
+        }
+        self.list_moves(py)
+    }
+
+    /// Search the current position with Monte Carlo Tree Search (as ai_move_mcts does, without
+    /// applying a move), returning a dict mapping each legal move (1-64) to the fraction of the
+    /// iterations that visited it.  Raises ValueError if iterations is 0.
+    fn mcts_policy(&self, iterations: usize, seed: Option<u64>, py: Python<'_>) -> PyResult<Py<PyDict>> {
+        if iterations == 0 {
+            return Err(PyValueError::new_err("iterations must be positive"));
+        }
+        let dict = PyDict::new_bound(py);
+        for (mov, visits) in MctsAI::new(iterations, seed).visit_counts(&self.game) {
+            dict.set_item(move_to_u8(Some(mov)), visits as f64 / iterations as f64)?;
+        }
+        Ok(dict.into())
+    }
+
+    /// Estimate the final scores (black_score, white_score) by playing the game out on a copy with
+    /// the AI at the given strength (as for ai_move) moving for both players.  This is only a
+    /// projection: it assumes both players play like the AI, and random play varies between calls.
+    fn projected_final_scores(&self, strength: i32, py: Python<'_>) -> PyResult<Py<PyTuple>> {
+        let game = play_to_end(self.game.clone(), self.capped_strength(strength), self.capped_strength(strength), &mut StdRng::from_entropy());
+        let (black_score, white_score) = game.scores();
+        Ok(PyTuple::new_bound(py, [black_score, white_score]).into())
+    }
+
+    /// Determine the AI's move at the given strength without applying it, returning
+    /// (move, forced).  The move is None if the current player has no legal moves, and forced is
+    /// true when the current player has exactly one legal move.
+    fn best_move_info(&self, strength: i32) -> PyResult<(Option<u8>, bool)> {
+        let num_moves = self.game.valid_moves(self.game.next_turn).len();
+        let best_move = self.choose_ai_move(&self.game, Some(strength)).map(|m| move_to_u8(Some(m)));
+        Ok((best_move, num_moves == 1))
+    }
+
+    /// Determine the AI's move at the given strength for the given player (1 for Black, 2 for
+    /// White), as if it were their turn, without changing this game.  Returns None if they have
+    /// no legal moves.
+    fn best_move_for(&self, player: u8, strength: i32) -> PyResult<Option<u8>> {
+        let mut game = self.game.clone();
+        game.next_turn = u8_to_colour(player)?;
+        Ok(self.choose_ai_move(&game, Some(strength)).map(|m| move_to_u8(Some(m))))
+    }
+
+    /// Determine the AI's move at the given strength (search depth) without applying it, returning
+    /// (move, margin) where margin is how much better the move scored than the second-best move.
+    /// The margin is 0 when there is only one legal move, and the move is None (with margin 0)
+    /// when there are none.  Raises ValueError if strength is not positive.
+    fn best_move_margin(&self, strength: i32) -> PyResult<(Option<u8>, i64)> {
+        let ai = AlphaBetaAI::new(self.capped_search_depth(strength)?);
+        let mut scored = ai.score_moves(&self.game);
+        // Stable sort keeps the last of equal scores last, which is the move choose_move picks
+        scored.sort_by_key(|(_, score)| *score);
+
+        let mut best = scored.iter().rev();
+        let Some((best_move, best_score)) = best.next() else { return Ok((None, 0)) };
+        let margin = best.next().map_or(0, |(_, second_score)| best_score - second_score);
+        Ok((Some(move_to_u8(Some(*best_move))), margin as i64))
+    }
+
+    /// Rate the given legal move (0 for a pass, 1-64 for a stone) for the current player against
+    /// the AI's best move at the given strength (search depth): the move's score minus the best
+    /// move's score, so 0 means the move is as good as the best and a negative value is a mistake.
+    /// Raises ValueError if the move is not legal or strength is not positive.
+    fn move_quality(&self, move_repr: u8, strength: i32) -> PyResult<i64> {
+        let ai = AlphaBetaAI::new(self.capped_search_depth(strength)?);
+        self.after_move(move_repr)?;
+        let scored = ai.score_moves(&self.game);
+
+        // A legal pass means there are no moves to compare it with
+        let Some(best_score) = scored.iter().map(|(_, score)| *score).max() else { return Ok(0) };
+        let score = scored.iter()
+            .find(|(mov, _)| move_to_u8(Some(*mov)) == move_repr)
+            .map(|(_, score)| *score)
+            .expect("legal move is scored");
+        Ok((score - best_score) as i64)
+    }
+
+    /// Score each legal move (1-64) for the current player at the given search depth and one ply
+    /// deeper (neither going beyond the maximum depth cap, if set), as a dict mapping each move to
+    /// (score at depth, score at depth + 1).  Moves whose scores differ a lot are tactically sharp.
+    /// Searching twice makes this slow, so it's meant for offline analysis.
+    /// Raises ValueError if depth is not positive.
+    fn candidate_depth_sensitivity(&self, depth: i32, py: Python<'_>) -> PyResult<Py<PyDict>> {
+        let shallow = AlphaBetaAI::new(self.capped_search_depth(depth)?).score_moves(&self.game);
+        let deep = AlphaBetaAI::new(self.capped_search_depth(depth.saturating_add(1))?).score_moves(&self.game);
+
+        let dict = PyDict::new_bound(py);
+        for ((mov, shallow_score), (_, deep_score)) in shallow.into_iter().zip(deep) {
+            dict.set_item(move_to_u8(Some(mov)), (shallow_score, deep_score))?;
+        }
+        Ok(dict.into())
+    }
+
+    /// Measure how open the position is for the current player, as the Shannon entropy (in bits) of
+    /// a softmax of the scores the AI gives their legal moves at the given strength (search depth).
+    /// It is near 0 when one move is much better than the rest, and up to log2 of the number of
+    /// moves when they are all about as good.  It is 0.0 if there are no legal moves.
+    /// Raises ValueError if strength is not positive.
+    fn move_entropy(&self, strength: i32) -> PyResult<f64> {
+        let ai = AlphaBetaAI::new(self.capped_search_depth(strength)?);
+        let scores: Vec<f64> = ai.score_moves(&self.game).into_iter().map(|(_, score)| score as f64).collect();
+        let best_score = scores.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+        // Subtracting the best score keeps the weights in range without changing the probabilities
+        let weights: Vec<f64> = scores.iter().map(|s| (s - best_score).exp()).collect();
+        let total: f64 = weights.iter().sum();
+        Ok(weights.iter()
+            .map(|w| w / total)
+            .filter(|&p| p > 0.0)
+            .map(|p| -p * p.log2())
+            .sum())
+    }
+
+    /// Determine the AI's move at the given strength (as for ai_move) in the position before the most
+    /// recent stone placement, i.e. what the AI would have played instead.  Returns None if no stones
+    /// have been placed yet.
+    fn previous_best_move(&self, strength: i32) -> PyResult<Option<u8>> {
+        let Some(last_placement) = self.move_history.iter().rposition(|&m| m != 0) else { return Ok(None) };
+        let before = &self.replay()[last_placement];
+        Ok(self.choose_ai_move(before, Some(strength)).map(|m| move_to_u8(Some(m))))
+    }
+
+    /// Determine the opponent's reply (at the given strength, as for ai_move) if the current player
+    /// made the given move, without changing this game.  Returns None if the opponent would have
+    /// no legal moves.  Raises ValueError if the move is not legal.
+    fn opponent_best_reply(&self, move_repr: u8, strength: i32) -> PyResult<Option<u8>> {
+        let after = self.after_move(move_repr)?;
+        Ok(self.choose_ai_move(&after.game, Some(strength)).map(|m| move_to_u8(Some(m))))
+    }
+
+    /// Find the current player's move leading to the most corners, searching to the given
+    /// depth with an evaluation that only counts corners held (rather than discs, as ai_move does).
+    /// Returns None if there are no legal moves.  Raises ValueError if depth is not positive.
+    fn corner_maximizing_move(&self, depth: i32) -> PyResult<Option<u8>> {
+        let ai = AlphaBetaAI::with_evaluation(self.capped_search_depth(depth)?, CornerEvaluation);
+        Ok(ai.choose_move(&self.game).map(|m| move_to_u8(Some(m))))
+    }
+
+    /// List the AI's best move (1-64, or 0 if the current player must pass) when searching to
+    /// each depth from 1 to max_depth.  A best move that keeps changing with depth is the sign of
+    /// a tricky, unstable position.  Raises ValueError if max_depth is not positive.
+    fn move_stability(&self, max_depth: i32, py: Python<'_>) -> PyResult<Py<PyList>> {
+        let best_moves: Vec<u8> = (1..=self.capped_search_depth(max_depth)?)
+            .map(|depth| move_to_u8(AlphaBetaAI::new(depth).choose_move(&self.game)))
+            .collect();
+        Ok(PyList::new_bound(py, best_moves).into())
+    }
+
     /// Get the current board state as a list of 64 integers.
     /// 0: Empty, 1: Black, 2: White
     #[getter]
     fn board(&self, py: Python<'_>) -> PyResult<Py<PyList>> {
-        let mut board_repr: Vec<u8> = Vec::with_capacity(64);
+        let board_repr = board_to_vec(&self.game.board);
+        // PyList::new is deprecated, use Bound API
+        Ok(PyList::new_bound(py, &board_repr).into())
+    }
+
+    /// List the occupied squares as (row, col, colour) tuples, where row and col are 0-7 and colour
+    /// is 1 for Black or 2 for White, in row order from a1.
+    fn occupied(&self, py: Python<'_>) -> PyResult<Py<PyList>> {
+        let mut squares: Vec<(Pos, Pos, u8)> = Vec::new();
         for r in 0..8 {
             for c in 0..8 {
-                let piece = self.game.board.get(r, c);
-                board_repr.push(match piece {
-                    None => 0,
-                    Some(Colour::Black) => 1,
-                    Some(Colour::White) => 2,
-                });
+                if let Some(colour) = self.game.board.get(r, c) {
+                    squares.push((r, c, colour_to_u8(colour)));
+                }
             }
         }
-        // PyList::new is deprecated, use Bound API
-        Ok(PyList::new_bound(py, &board_repr).into())
+        Ok(PyList::new_bound(py, squares).into())
+    }
+
+    /// Get the board that would result from the given legal move (0 for a pass, 1-64 for a
+    /// stone), as a list of 64 integers like board, without changing this game.
+    /// Raises ValueError if the move is not legal.
+    fn preview_board(&self, move_repr: u8, py: Python<'_>) -> PyResult<Py<PyList>> {
+        let after = self.after_move(move_repr)?;
+        Ok(PyList::new_bound(py, board_to_vec(&after.game.board)).into())
+    }
+
+    /// Play a line of moves (0 for a pass, 1-64 for a stone) on a copy of this game, without changing
+    /// it, returning (board, black_score, white_score, game_over) for the resulting position, where
+    /// board is a list of 64 integers like board.
+    /// Raises ValueError, giving the index of the offending move, at the first illegal move.
+    fn simulate(&self, moves: Vec<u8>, py: Python<'_>) -> PyResult<Py<PyTuple>> {
+        let mut game = self.scratch_copy();
+        for (i, move_repr) in moves.into_iter().enumerate() {
+            if !game.add_stone(move_repr)? {
+                return Err(PyValueError::new_err(format!("Illegal move {} at index {}", move_repr, i)));
+            }
+        }
+
+        let (black_score, white_score) = game.game.scores();
+        let board = board_to_vec(&game.game.board);
+        let game_over = game.is_game_over()?;
+        Ok(PyTuple::new_bound(py, &[board.into_py(py), black_score.into_py(py), white_score.into_py(py), game_over.into_py(py)]).into())
+    }
+
+    /// Get the scores (black_score, white_score) that would result from the given legal move
+    /// (0 for a pass, 1-64 for a stone), without changing this game.
+    /// Raises ValueError if the move is not legal.
+    fn scores_after(&self, move_repr: u8, py: Python<'_>) -> PyResult<Py<PyTuple>> {
+        self.after_move(move_repr)?.scores(py)
+    }
+
+    /// Check whether this game and the other have reached the same position, with the same board and
+    /// the same player to move, whatever moves were played to get there.
+    fn is_transposition_of(&self, other: &PyOthelloGame) -> bool {
+        self.game == other.game
+    }
+
+    /// List the squares whose contents differ between this game's board and the other's, as
+    /// (index, value) tuples where index is 0-63 and value is the square's contents in the other
+    /// board (0: Empty, 1: Black, 2: White), i.e. the changes going from this position to the other.
+    fn board_diff(&self, other: &PyOthelloGame, py: Python<'_>) -> PyResult<Py<PyList>> {
+        let changes: Vec<(u8, u8)> = board_to_vec(&self.game.board).into_iter()
+            .zip(board_to_vec(&other.game.board))
+            .enumerate()
+            .filter(|(_, (before, after))| before != after)
+            .map(|(i, (_, after))| (i as u8, after))
+            .collect();
+        Ok(PyList::new_bound(py, changes).into())
     }
 
     /// Get the color of the next player (1 for Black, 2 for White).
     #[getter]
     fn next_player(&self) -> PyResult<u8> {
-        Ok(match self.game.next_turn {
-            Colour::Black => 1,
-            Colour::White => 2,
-        })
+        Ok(colour_to_u8(self.game.next_turn))
+    }
+
+    /// Get the name of the next player, "Black" or "White".
+    fn next_player_name(&self) -> String {
+        colour_name(self.game.next_turn).to_string()
     }
 
     /// Get the current scores as a tuple (black_score, white_score).
@@ -191,10 +1429,21 @@ impl PyOthelloGame {
         Ok(PyTuple::new_bound(py, &[scores.0.into_py(py), scores.1.into_py(py)]).into())
     }
 
+    /// Get the number of discs the given player (1 for Black, 2 for White) has on the board.
+    fn disc_count(&self, player: u8) -> PyResult<u32> {
+        let (black_score, white_score) = self.game.scores();
+        let discs = if u8_to_colour(player)? == Colour::Black { black_score } else { white_score };
+        Ok(discs as u32)
+    }
+
 
-    /// Check if the game is over (neither player has any valid moves).
+    /// Check if the game is over (neither player has any valid moves, both players have just
+    /// passed, or a draw has been agreed).
     #[getter]
     fn is_game_over(&self) -> PyResult<bool> {
+        if self.draw_agreed || self.double_pass_ended() {
+            return Ok(true);
+        }
         let current_player_has_moves = self.game.valid_moves(self.game.next_turn).into_iter().next().is_some();
         if current_player_has_moves {
             Ok(false) // Current player can move, game not over
@@ -205,31 +1454,696 @@ impl PyOthelloGame {
         }
     }
 
+    /// Get the winner of a finished game: 1 for Black, 2 for White, or 0 for a draw (including an
+    /// agreed draw).  None if the game isn't over.
+    #[getter]
+    fn winner(&self) -> PyResult<Option<u8>> {
+        if !self.is_game_over()? {
+            return Ok(None);
+        }
+        if self.draw_agreed {
+            return Ok(Some(0));
+        }
+        let (black_score, white_score) = self.game.scores();
+        Ok(Some(match black_score.cmp(&white_score) {
+            std::cmp::Ordering::Greater => colour_to_u8(Colour::Black),
+            std::cmp::Ordering::Less => colour_to_u8(Colour::White),
+            std::cmp::Ordering::Equal => 0,
+        }))
+    }
+
+    /// Check whether the game has ended in a draw, with equal disc counts or by agreement, i.e.
+    /// whether winner is 0.
+    fn is_drawn(&self) -> PyResult<bool> {
+        Ok(self.winner()? == Some(0))
+    }
+
+    /// Offer a draw on behalf of the given player (1 for Black, 2 for White).  Only the latest offer
+    /// counts, and, as over the board, it lapses if the next move (or pass) is made instead of the
+    /// opponent accepting it with accept_draw.  Once a draw is agreed, no more moves can be made.
+    fn offer_draw(&mut self, player: u8) -> PyResult<()> {
+        self.draw_offered_by = Some(u8_to_colour(player)?);
+        Ok(())
+    }
+
+    /// Accept a draw on behalf of the given player (1 for Black, 2 for White), which ends the game
+    /// as a draw if their opponent has offered one.  Returns true if the draw was agreed.
+    fn accept_draw(&mut self, player: u8) -> PyResult<bool> {
+        let colour = u8_to_colour(player)?;
+        if self.draw_offered_by == Some(colour.opponent()) {
+            self.draw_offered_by = None;
+            self.draw_agreed = true;
+        }
+        Ok(self.draw_agreed)
+    }
+
+    /// Check whether the given player (1 for Black, 2 for White) could possibly move again.  This
+    /// is a conservative check: it is false only when the player has no discs left (so can never
+    /// bracket a disc) or the board is full, and true otherwise, even if the player is in fact stuck.
+    fn can_ever_move(&self, player: u8) -> PyResult<bool> {
+        let colour = u8_to_colour(player)?;
+        let (black_score, white_score) = self.game.scores();
+        let own_discs = if colour == Colour::Black { black_score } else { white_score };
+        Ok(own_discs > 0 && empty_count(&self.game) > 0)
+    }
+
+    /// Get the player (1 for Black, 2 for White) who looks to be dominating the game, by holding all
+    /// four corners or at least 48 discs, or None if neither does.  This is only a heuristic for
+    /// guessing the likely winner early: it's no guarantee that the dominating player will win.
+    fn dominance(&self) -> PyResult<Option<u8>> {
+        let (black_score, white_score) = self.game.scores();
+        let holds_corners = |colour: Colour| CORNERS.iter()
+            .all(|&c| self.game.board.get(((c - 1) / 8) as Pos, ((c - 1) % 8) as Pos) == Some(colour));
+        Ok([(Colour::Black, black_score), (Colour::White, white_score)].into_iter()
+            .find(|&(colour, discs)| holds_corners(colour) || discs >= DOMINANT_DISCS)
+            .map(|(colour, _)| colour_to_u8(colour)))
+    }
+
+    /// Get the player (1 for Black, 2 for White) who has wiped out their opponent, leaving them with
+    /// no discs and so ending the game, or None if both players still have discs.
+    fn wipeout_winner(&self) -> PyResult<Option<u8>> {
+        Ok(match self.game.scores() {
+            (black_score, 0) if black_score > 0 => Some(colour_to_u8(Colour::Black)),
+            (0, white_score) if white_score > 0 => Some(colour_to_u8(Colour::White)),
+            _ => None,
+        })
+    }
+
+    /// Check if the last two moves were both passes, which ends the game.
+    fn double_pass_ended(&self) -> bool {
+        self.move_history.ends_with(&[0, 0])
+    }
+
+    /// Get a quick positional score for the given player (1 for Black, 2 for White): the sum of
+    /// their discs' weights minus the opponent's, where squares weigh from 1 in the centre to 7 in
+    /// the corners by their distance from the centre.
+    fn centre_control(&self, player: u8) -> PyResult<i64> {
+        let colour = u8_to_colour(player)?;
+        Ok(evaluate_centre_distance(&self.game, colour) as i64)
+    }
+
+    /// Get a positional score for the given player (1 for Black, 2 for White): the sum of the
+    /// weights (see positional_weights) of the squares holding their discs minus the opponent's.
+    fn positional_score(&self, player: u8) -> PyResult<i64> {
+        let colour = u8_to_colour(player)?;
+        Ok(evaluate_positional(&self.game, colour) as i64)
+    }
+
+    /// Break down the AI's evaluation of the position after the given legal move (0 for a pass, 1-64
+    /// for a stone), from the point of view of the player making it, as a dict with its "corners",
+    /// "mobility", "discs" and "stability" components and their "total".  This is the evaluation
+    /// ai_move scores positions with at the end of its search, which counts discs alone, so the other
+    /// components are always 0.  Raises ValueError if the move is not legal.
+    fn evaluate_components(&self, move_repr: u8, py: Python<'_>) -> PyResult<Py<PyDict>> {
+        let after = self.after_move(move_repr)?;
+        let components = DiscEvaluation.components(&after.game, self.game.next_turn);
+
+        let dict = PyDict::new_bound(py);
+        dict.set_item("corners", components.corners)?;
+        dict.set_item("mobility", components.mobility)?;
+        dict.set_item("discs", components.discs)?;
+        dict.set_item("stability", components.stability)?;
+        dict.set_item("total", components.total())?;
+        Ok(dict.into())
+    }
+
+    /// Evaluate the current position from both players' points of view with the evaluation ai_move
+    /// scores positions with at the end of its search, returning (black_eval, white_eval).  As that
+    /// evaluation is the disc difference, which is zero-sum, white_eval is always -black_eval.
+    fn evaluate_both(&self, py: Python<'_>) -> PyResult<Py<PyTuple>> {
+        let black_eval = DiscEvaluation.evaluate(&self.game, Colour::Black);
+        let white_eval = DiscEvaluation.evaluate(&self.game, Colour::White);
+        Ok(PyTuple::new_bound(py, [black_eval, white_eval]).into())
+    }
+
+    /// Get the number of legal replies the opponent would have after each legal move for the current
+    /// player, as a dict mapping each move (1-64) to its reply count.  An empty dict means the
+    /// current player must pass.
+    fn opponent_reply_counts(&self, py: Python<'_>) -> PyResult<Py<PyDict>> {
+        let dict = PyDict::new_bound(py);
+        for mov in self.game.valid_moves(self.game.next_turn) {
+            let replies = self.game.apply(mov).valid_moves(mov.player.opponent()).len();
+            dict.set_item(move_to_u8(Some(mov)), replies)?;
+        }
+        Ok(dict.into())
+    }
+
+    /// Measure how constraining a stone placement (1-64) is: 1.0 minus the number of replies the
+    /// opponent would have divided by the number of legal moves the current player has now.
+    /// Higher values are more forcing.  Raises ValueError if the move is not a legal placement.
+    fn tempo_pressure(&self, move_repr: u8) -> PyResult<f64> {
+        if move_repr == 0 {
+            return Err(PyValueError::new_err("tempo_pressure needs a stone placement, not a pass"));
+        }
+        let after = self.after_move(move_repr)?;
+        let current_count = self.game.valid_moves(self.game.next_turn).len();
+        let reply_count = after.game.valid_moves(self.game.next_turn.opponent()).len();
+        Ok(1.0 - reply_count as f64 / current_count as f64)
+    }
+
+    /// Check whether the disc on the given square (0-63) can never be flipped for the rest of the
+    /// game, as found by propagating stability out from the edges of the board.
+    /// Raises ValueError if the square is empty or out of range.
+    fn is_stable(&self, index: u8) -> PyResult<bool> {
+        if index >= 64 {
+            return Err(PyValueError::new_err(format!("Square must be between 0 and 63, got {}", index)));
+        }
+        let (row, col) = ((index / 8) as Pos, (index % 8) as Pos);
+        if self.game.board.get(row, col).is_none() {
+            return Err(PyValueError::new_err(format!("Square {} is empty", index)));
+        }
+        Ok(stable_discs(&self.game)[row as usize][col as usize])
+    }
+
+    /// Get the given player's (1 for Black, 2 for White) stable discs (see is_stable) minus their
+    /// opponent's.
+    fn stability_advantage(&self, player: u8) -> PyResult<i64> {
+        let colour = u8_to_colour(player)?;
+        Ok(evaluate_stability(&self.game, colour) as i64)
+    }
+
+    /// Get the (black, white) disc counts among the four centre squares d4, d5, e4 and e5.
+    fn centre_control_2x2(&self, py: Python<'_>) -> PyResult<Py<PyTuple>> {
+        let (mut black_count, mut white_count): (Score, Score) = (0, 0);
+        for (r, c) in [(3, 3), (4, 3), (3, 4), (4, 4)] {
+            match self.game.board.get(r, c) {
+                Some(Colour::Black) => black_count += 1,
+                Some(Colour::White) => white_count += 1,
+                None => (),
+            }
+        }
+        Ok(PyTuple::new_bound(py, &[black_count.into_py(py), white_count.into_py(py)]).into())
+    }
+
+    /// Get the contents (0 for empty, 1 for Black, 2 for White) of the X-squares, those diagonally
+    /// next to the corners, in the order b2, b7, g2, g7.
+    fn x_squares(&self, py: Python<'_>) -> PyResult<Py<PyList>> {
+        let pieces: Vec<u8> = [(1, 1), (6, 1), (1, 6), (6, 6)].into_iter()
+            .map(|(r, c)| piece_to_u8(self.game.board.get(r, c)))
+            .collect();
+        Ok(PyList::new_bound(py, pieces).into())
+    }
+
+    /// Get the (black, white) disc counts in each 4x4 quadrant of the board, ordered
+    /// top-left, top-right, bottom-left, bottom-right.
+    fn quadrant_scores(&self, py: Python<'_>) -> PyResult<Py<PyList>> {
+        let mut quadrants: [(Score, Score); 4] = [(0, 0); 4];
+        for r in 0..8 {
+            for c in 0..8 {
+                let quadrant = &mut quadrants[(r / 4 * 2 + c / 4) as usize];
+                match self.game.board.get(r, c) {
+                    Some(Colour::Black) => quadrant.0 += 1,
+                    Some(Colour::White) => quadrant.1 += 1,
+                    None => (),
+                }
+            }
+        }
+        Ok(PyList::new_bound(py, quadrants).into())
+    }
+
+    /// Get the occupancy of the four edges as a dict mapping "top", "bottom", "left" and "right"
+    /// to 8-character strings of '.', 'B' and 'W'.  Top and bottom read left to right, left and
+    /// right read top to bottom.
+    fn edges(&self, py: Python<'_>) -> PyResult<Py<PyDict>> {
+        let board = &self.game.board;
+        let dict = PyDict::new_bound(py);
+        dict.set_item("top", line_string(board, (0..8).map(|c| (0, c))))?;
+        dict.set_item("bottom", line_string(board, (0..8).map(|c| (7, c))))?;
+        dict.set_item("left", line_string(board, (0..8).map(|r| (r, 0))))?;
+        dict.set_item("right", line_string(board, (0..8).map(|r| (r, 7))))?;
+        Ok(dict.into())
+    }
+
+    /// Get the occupancy of the two long diagonals as a dict mapping "a1-h8" and "a8-h1" to
+    /// 8-character strings of '.', 'B' and 'W', each read from the first square named to the second.
+    fn diagonals(&self, py: Python<'_>) -> PyResult<Py<PyDict>> {
+        let board = &self.game.board;
+        let dict = PyDict::new_bound(py);
+        dict.set_item("a1-h8", line_string(board, (0..8).map(|i| (i, i))))?;
+        dict.set_item("a8-h1", line_string(board, (0..8).map(|i| (7 - i, i))))?;
+        Ok(dict.into())
+    }
+
+    /// Get which colour, if any, occupies the whole of each edge, as a dict mapping "top", "bottom",
+    /// "left" and "right" to 1 (all Black), 2 (all White) or 0 (mixed or not full).
+    fn owned_edges(&self, py: Python<'_>) -> PyResult<Py<PyDict>> {
+        let board = &self.game.board;
+        let dict = PyDict::new_bound(py);
+        dict.set_item("top", line_owner(board, (0..8).map(|c| (0, c))))?;
+        dict.set_item("bottom", line_owner(board, (0..8).map(|c| (7, c))))?;
+        dict.set_item("left", line_owner(board, (0..8).map(|r| (r, 0))))?;
+        dict.set_item("right", line_owner(board, (0..8).map(|r| (r, 7))))?;
+        Ok(dict.into())
+    }
+
+    /// Classify each legal move for the current player as "win", "draw" or "loss" for that
+    /// player, by solving the resulting position exactly with perfect play from both sides.
+    /// Only available near the end of the game; raises ValueError if more than 8 squares are empty.
+    fn endgame_classification(&self, py: Python<'_>) -> PyResult<Py<PyDict>> {
+        if empty_count(&self.game) > MAX_SOLVE_EMPTIES {
+            return Err(PyValueError::new_err("too many empties"));
+        }
+
+        let dict = PyDict::new_bound(py);
+        for mov in self.game.valid_moves(self.game.next_turn) {
+            // The solved score is from the opponent's point of view, as they move next
+            let score = -solve(&self.game.apply(mov));
+            let result = match score {
+                s if s > 0 => "win",
+                0 => "draw",
+                _ => "loss",
+            };
+            dict.set_item(move_to_u8(Some(mov)), result)?;
+        }
+        Ok(dict.into())
+    }
+
+    /// Get the sizes of the connected regions of empty squares (including diagonal neighbours),
+    /// in the order each region's first square appears scanning from a1.  Odd-sized regions are
+    /// what matter for endgame parity.
+    fn region_parities(&self, py: Python<'_>) -> PyResult<Py<PyList>> {
+        let mut seen = [[false; 8]; 8];
+        let mut sizes: Vec<usize> = Vec::new();
+        for r in 0..8 {
+            for c in 0..8 {
+                if seen[r as usize][c as usize] || self.game.board.get(r, c).is_some() {
+                    continue;
+                }
+
+                // Flood fill this region
+                let mut size = 0;
+                let mut stack = vec![(r, c)];
+                seen[r as usize][c as usize] = true;
+                while let Some((row, col)) = stack.pop() {
+                    size += 1;
+                    for (dr, dc) in DIRECTIONS {
+                        let (nr, nc) = (row + dr, col + dc);
+                        if !(0..8).contains(&nr) || !(0..8).contains(&nc) {
+                            continue;
+                        }
+                        if !seen[nr as usize][nc as usize] && self.game.board.get(nr, nc).is_none() {
+                            seen[nr as usize][nc as usize] = true;
+                            stack.push((nr, nc));
+                        }
+                    }
+                }
+                sizes.push(size);
+            }
+        }
+        Ok(PyList::new_bound(py, &sizes).into())
+    }
+
+    /// List the empty squares (0-63) with no disc of either colour on any of the eight neighbouring
+    /// squares.  Neither player can currently play there.  This is only an approximation of the
+    /// squares that can never be played: most of them become playable once discs arrive nearby.
+    fn dead_squares(&self, py: Python<'_>) -> PyResult<Py<PyList>> {
+        let board = &self.game.board;
+        let squares: Vec<u8> = (0..64u8)
+            .filter(|&i| {
+                let (r, c) = ((i / 8) as Pos, (i % 8) as Pos);
+                board.get(r, c).is_none() && DIRECTIONS.iter().all(|(dr, dc)| {
+                    let (nr, nc) = (r + dr, c + dc);
+                    !(0..8).contains(&nr) || !(0..8).contains(&nc) || board.get(nr, nc).is_none()
+                })
+            })
+            .collect();
+        Ok(PyList::new_bound(py, squares).into())
+    }
+
+    /// List the legal moves for the current player as dicts with keys "row", "col" (0-7),
+    /// "player" (1 for Black, 2 for White), "move_repr" (1-64) and "flips" (number of discs flipped).
+    fn valid_moves_detailed(&self, py: Python<'_>) -> PyResult<Py<PyList>> {
+        let list = PyList::empty_bound(py);
+        for mov in self.game.valid_moves(self.game.next_turn) {
+            let dict = PyDict::new_bound(py);
+            dict.set_item("row", mov.row)?;
+            dict.set_item("col", mov.col)?;
+            dict.set_item("player", colour_to_u8(mov.player))?;
+            dict.set_item("move_repr", move_to_u8(Some(mov)))?;
+            dict.set_item("flips", flipped_squares(&self.game, mov).len())?;
+            list.append(dict)?;
+        }
+        Ok(list.into())
+    }
+
+    /// Count the discs the current player could flip, summed over all of their legal moves, so a
+    /// disc that several moves would flip is counted once for each of them.
+    fn total_capturable(&self) -> PyResult<u32> {
+        Ok(self.game.valid_moves(self.game.next_turn)
+            .into_iter()
+            .map(|m| flipped_squares(&self.game, m).len() as u32)
+            .sum())
+    }
+
+    /// Check whether the given legal move (0 for a pass, 1-64 for a stone) is a quiet move, flipping
+    /// exactly one disc.  A pass is never quiet.  Raises ValueError if the move is not legal.
+    fn is_quiet_move(&self, move_repr: u8) -> PyResult<bool> {
+        self.after_move(move_repr)?;
+        Ok(u8_to_move(move_repr, self.game.next_turn)?.is_some_and(|m| flipped_squares(&self.game, m).len() == 1))
+    }
+
+    /// List the legal moves (1-64) for the current player after which neither player could move,
+    /// ending the game.
+    fn terminating_moves(&self, py: Python<'_>) -> PyResult<Py<PyList>> {
+        let moves: Vec<u8> = self.game.valid_moves(self.game.next_turn)
+            .into_iter()
+            .filter(|&m| {
+                let after = self.game.apply(m);
+                after.valid_moves(Colour::Black).is_empty() && after.valid_moves(Colour::White).is_empty()
+            })
+            .map(|m| move_to_u8(Some(m)))
+            .collect();
+        Ok(PyList::new_bound(py, moves).into())
+    }
+
+    /// List the positions one move from the end of the game, as (move, game) tuples of each legal
+    /// move (1-64) for the current player that ends the game (see terminating_moves) and a new
+    /// OthelloGame with it played.
+    fn near_terminal_children(&self, py: Python<'_>) -> PyResult<Py<PyList>> {
+        let list = PyList::empty_bound(py);
+        for mov in self.game.valid_moves(self.game.next_turn) {
+            let move_repr = move_to_u8(Some(mov));
+            let child = self.after_move(move_repr)?;
+            if child.is_game_over()? {
+                list.append((move_repr, Py::new(py, child)?))?;
+            }
+        }
+        Ok(list.into())
+    }
+
+    /// List the legal moves (1-64) for the current player that would flip the disc on the given
+    /// square (0-63).  Raises ValueError if the square is out of range.
+    fn moves_flipping(&self, target_index: u8, py: Python<'_>) -> PyResult<Py<PyList>> {
+        if target_index >= 64 {
+            return Err(PyValueError::new_err(format!("Square must be between 0 and 63, got {}", target_index)));
+        }
+        let moves: Vec<u8> = self.game.valid_moves(self.game.next_turn)
+            .into_iter()
+            .filter(|&m| flipped_squares(&self.game, m).contains(&target_index))
+            .map(|m| move_to_u8(Some(m)))
+            .collect();
+        Ok(PyList::new_bound(py, moves).into())
+    }
+
+    /// List the legal moves (1-64) for the current player that flip discs along at least
+    /// min_directions of the eight directions from the square played.
+    fn multi_direction_moves(&self, min_directions: usize, py: Python<'_>) -> PyResult<Py<PyList>> {
+        let moves: Vec<u8> = self.game.valid_moves(self.game.next_turn)
+            .into_iter()
+            .filter(|&m| flips_by_direction(&self.game, m).len() >= min_directions)
+            .map(|m| move_to_u8(Some(m)))
+            .collect();
+        Ok(PyList::new_bound(py, moves).into())
+    }
+
+    /// Explain how the given legal move (0 for a pass, 1-64 for a stone) captures, as a list of
+    /// (direction, start, end) tuples, one for each run of discs it flips, where direction is the
+    /// way the run goes from the move, e.g. "up-left" or "right" (as render_labeled draws the board,
+    /// with row 1 at the top), and start and end are the squares (0-63) of the run's nearest and
+    /// furthest discs.  A pass flips nothing.  Raises ValueError if the move is not legal.
+    fn bracket_explanation(&self, move_repr: u8, py: Python<'_>) -> PyResult<Py<PyList>> {
+        self.after_move(move_repr)?;
+        let Some(mov) = u8_to_move(move_repr, self.game.next_turn)? else {
+            return Ok(PyList::empty_bound(py).into());
+        };
+        let runs: Vec<(&str, u8, u8)> = flips_by_direction(&self.game, mov)
+            .into_iter()
+            .map(|(direction, run)| {
+                let i = DIRECTIONS.iter().position(|&d| d == direction).expect("one of the directions");
+                (DIRECTION_NAMES[i], run[0], run[run.len() - 1])
+            })
+            .collect();
+        Ok(PyList::new_bound(py, runs).into())
+    }
+
+    /// Get the opponent's disc count minus the given player's (1 for Black, 2 for White), so
+    /// higher is better under the disc-minimization strategy.
+    fn disc_minimization_score(&self, player: u8) -> PyResult<i32> {
+        let colour = u8_to_colour(player)?;
+        let (black_score, white_score) = self.game.scores();
+        Ok((white_score - black_score) * colour.sign())
+    }
+
+    /// Count, for each square (0-63), how many times its contents changed over the game so far,
+    /// i.e. the stone being placed plus every later flip.  The four starting discs aren't counted
+    /// as placements.
+    fn flip_heatmap(&self, py: Python<'_>) -> PyResult<Py<PyList>> {
+        let mut counts = [0u32; 64];
+        for pair in self.replay().windows(2) {
+            let (before, after) = (&pair[0].board, &pair[1].board);
+            for (i, count) in counts.iter_mut().enumerate() {
+                let (r, c) = ((i / 8) as Pos, (i % 8) as Pos);
+                if before.get(r, c) != after.get(r, c) {
+                    *count += 1;
+                }
+            }
+        }
+        Ok(PyList::new_bound(py, counts).into())
+    }
+
+    /// Estimate how many more plies the game will last, as the average length of `samples` games
+    /// played out randomly from the current position (seeded by `seed`), counting passes as plies.
+    /// Raises ValueError if samples is 0.
+    fn estimated_plies_remaining(&self, samples: usize, seed: u64) -> PyResult<f64> {
+        if samples == 0 {
+            return Err(PyValueError::new_err("samples must be positive"));
+        }
+        let mut rng = StdRng::seed_from_u64(seed);
+        let total_plies: usize = (0..samples).map(|_| random_playout(&self.game, &mut rng).1).sum();
+        Ok(total_plies as f64 / samples as f64)
+    }
+
+    /// Estimate the given player's (1 for Black, 2 for White) chances from the current position, as
+    /// the fraction of `samples` games played out randomly (seeded by `seed`) that they win, with
+    /// draws counting as half a win.  Raises ValueError if samples is 0.
+    fn rollout_winrate(&self, samples: usize, for_player: u8, seed: u64) -> PyResult<f64> {
+        let colour = u8_to_colour(for_player)?;
+        if samples == 0 {
+            return Err(PyValueError::new_err("samples must be positive"));
+        }
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut wins = 0.0;
+        for _ in 0..samples {
+            let (game, _) = random_playout(&self.game, &mut rng);
+            let (black_score, white_score) = game.scores();
+            wins += match ((black_score - white_score) * colour.sign()).signum() {
+                1 => 1.0,
+                0 => 0.5,
+                _ => 0.0,
+            };
+        }
+        Ok(wins / samples as f64)
+    }
+
+    /// Evaluate each move so far from the point of view of the player who made it, searching to the
+    /// given strength (depth), as (move, before, after) tuples: before is the score of their best
+    /// move, and after the score of the move actually played, so a big drop is a blunder.  For a
+    /// pass both are the value of the position.  Raises ValueError if strength is not positive.
+    fn swing_analysis(&self, strength: i32, py: Python<'_>) -> PyResult<Py<PyList>> {
+        let ai = AlphaBetaAI::new(self.capped_search_depth(strength)?);
+        let mut swings: Vec<(u8, Score, Score)> = Vec::new();
+        for (game, &move_repr) in self.replay().iter().zip(&self.move_history) {
+            let scored = ai.score_moves(game);
+            let swing = match scored.iter().map(|(_, score)| *score).max() {
+                Some(best_score) => {
+                    let played_score = scored.iter()
+                        .find(|(mov, _)| move_to_u8(Some(*mov)) == move_repr)
+                        .map_or(best_score, |(_, score)| *score);
+                    (move_repr, best_score, played_score)
+                }
+                None => {
+                    let value = position_value(game, &ai);
+                    (move_repr, value, value)
+                }
+            };
+            swings.push(swing);
+        }
+        Ok(PyList::new_bound(py, swings).into())
+    }
+
+    /// List the number of legal moves each player had after each move so far, as
+    /// (black_mobility, white_mobility) tuples, one for each entry in the move history.
+    fn mobility_history(&self, py: Python<'_>) -> PyResult<Py<PyList>> {
+        let mobility: Vec<(usize, usize)> = self.replay().iter()
+            .skip(1)
+            .map(|game| (game.valid_moves(Colour::Black).len(), game.valid_moves(Colour::White).len()))
+            .collect();
+        Ok(PyList::new_bound(py, mobility).into())
+    }
+
+    /// Check whether the given player (1 for Black, 2 for White) can force a win, finishing the
+    /// game with more discs whatever the opponent plays, within the given number of plies (passes
+    /// count as plies).  This is an exact search, so it gets expensive as the number of plies grows.
+    fn is_forced_win(&self, within_plies: usize, for_player: u8) -> PyResult<bool> {
+        let colour = u8_to_colour(for_player)?;
+        Ok(can_force_win(&self.game, colour, within_plies))
+    }
+
+    /// Summarise the game in one dict, with keys "moves" (as list_moves), "next_player",
+    /// "black_score", "white_score", "game_over", "winner" (as the winner getter) and
+    /// "black_mobility" and "white_mobility" (each player's number of legal moves).
+    fn summary(&self, py: Python<'_>) -> PyResult<Py<PyDict>> {
+        let (black_score, white_score) = self.game.scores();
+        let dict = PyDict::new_bound(py);
+        dict.set_item("moves", &self.move_history)?;
+        dict.set_item("next_player", self.next_player()?)?;
+        dict.set_item("black_score", black_score)?;
+        dict.set_item("white_score", white_score)?;
+        dict.set_item("game_over", self.is_game_over()?)?;
+        dict.set_item("winner", self.winner()?)?;
+        dict.set_item("black_mobility", self.game.valid_moves(Colour::Black).len())?;
+        dict.set_item("white_mobility", self.game.valid_moves(Colour::White).len())?;
+        Ok(dict.into())
+    }
+
+    /// Describe the position in words, for screen readers, e.g. "Black to move. Black has 12 discs,
+    /// White 8. Corners: h1 Black, others empty. Legal moves: c4, f5."
+    fn describe(&self) -> PyResult<String> {
+        let (black_score, white_score) = self.game.scores();
+        let turn = if self.is_game_over()? {
+            "Game over.".to_string()
+        } else {
+            format!("{} to move.", colour_name(self.game.next_turn))
+        };
+
+        let mut corners: Vec<String> = Vec::new();
+        for &corner in &CORNERS {
+            let (row, col) = (((corner - 1) / 8) as Pos, ((corner - 1) % 8) as Pos);
+            if let Some(colour) = self.game.board.get(row, col) {
+                corners.push(format!("{} {}", square_name(row, col), colour_name(colour)));
+            }
+        }
+        let corners = match corners.len() {
+            0 => "all empty".to_string(),
+            4 => corners.join(", "),
+            _ => format!("{}, others empty", corners.join(", ")),
+        };
+
+        let moves: Vec<String> = self.game.valid_moves(self.game.next_turn)
+            .into_iter()
+            .map(|m| square_name(m.row, m.col))
+            .collect();
+        let moves = if moves.is_empty() { "none".to_string() } else { moves.join(", ") };
+
+        Ok(format!("{} Black has {} discs, White {}. Corners: {}. Legal moves: {}.",
+                   turn, black_score, white_score, corners, moves))
+    }
+
+    /// Render the board as __str__ does, but with the squares spaced out and labelled with column
+    /// letters (a-h) along the top and row numbers (1-8) down the side, matching square names like e3.
+    fn render_labeled(&self) -> String {
+        let mut s = String::from("  a b c d e f g h\n");
+        for r in 0..8 {
+            s.push_str(&(r + 1).to_string());
+            for c in 0..8 {
+                s.push(' ');
+                s.push(piece_char(self.game.board.get(r, c)));
+            }
+            s.push('\n');
+        }
+        let scores = self.game.scores();
+        s.push_str(&format!("Score: B {} - W {}\n", scores.0, scores.1));
+        s.push_str(&format!("Next Turn: {}\n", colour_name(self.game.next_turn)));
+        s
+    }
+
     // Implement __str__ manually since Game doesn't implement Display
     fn __str__(&self) -> String {
         let mut s = String::with_capacity(8 * 9); // 8 rows * (8 chars + newline)
          for r in 0..8 {
              for c in 0..8 {
-                 let piece = self.game.board.get(r, c);
-                 s.push(match piece {
-                     Some(Colour::Black) => 'B', // Using B/W for clarity
-                     Some(Colour::White) => 'W',
-                     _ => '.',
-                 });
+                 s.push(piece_char(self.game.board.get(r, c)));
              }
              s.push('\n');
          }
         // Add score and next player info
         let scores = self.game.scores();
          s.push_str(&format!("Score: B {} - W {}\n", scores.0, scores.1));
-         s.push_str(&format!("Next Turn: {}\n", if self.game.next_turn == Colour::Black {"Black"} else {"White"}));
+         s.push_str(&format!("Next Turn: {}\n", colour_name(self.game.next_turn)));
         s
     }
 }
 
+/// Count the leaf nodes of the game tree from the start position to exactly the given depth in
+/// plies, counting forced passes as plies, to check move generation against known counts.
+#[pyfunction]
+fn perft(depth: usize) -> PyResult<u64> {
+    Ok(perft_count(&DefaultGame::new(), depth))
+}
+
+/// Search `positions` random positions (generated from `seed`) with AlphaBetaAI to the given depth.
+/// Returns (total_nodes, seconds) so nodes-per-second can be tracked across versions.
+#[pyfunction]
+fn benchmark_search(depth: i32, positions: usize, seed: u64) -> PyResult<(u64, f64)> {
+    let depth = search_depth(depth)?;
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let games: Vec<DefaultGame> = (0..positions).map(|_| random_position(&mut rng)).collect();
+
+    let ai = AlphaBetaAI::new(depth);
+    let start = Instant::now();
+    for game in &games {
+        ai.choose_move(game);
+    }
+    let seconds = start.elapsed().as_secs_f64();
+
+    let nodes = ai.info().map_or(0, |info| info.nodes_searched.load(Ordering::Relaxed));
+    Ok((nodes as u64, seconds))
+}
+
+/// Get the weight of each square (0-63) used by OthelloGame.positional_score, in row order from
+/// a1: corners are worth most, and the squares next to them are negative as they give corners away.
+#[pyfunction]
+fn positional_weights(py: Python<'_>) -> PyResult<Py<PyList>> {
+    let weights: Vec<Score> = POSITIONAL_WEIGHTS.iter().flatten().copied().collect();
+    Ok(PyList::new_bound(py, weights).into())
+}
+
+/// Play `games` pairs of games between AIs of strengths A and B (as for ai_move), with A playing
+/// Black in the first game of each pair and White in the second.  Random play is seeded by `seed`.
+/// Returns a dict with "a_wins", "b_wins", "draws" and "avg_margin", the average of A's disc count
+/// minus B's over all games played.
+#[pyfunction]
+fn compare_ais(py: Python<'_>, strength_a: i32, strength_b: i32, games: usize, seed: u64) -> PyResult<Py<PyDict>> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let (mut a_wins, mut b_wins, mut draws) = (0, 0, 0);
+    let mut total_margin: i64 = 0;
+
+    for _ in 0..games {
+        for a_colour in [Colour::Black, Colour::White] {
+            let (black_strength, white_strength) = match a_colour {
+                Colour::Black => (strength_a, strength_b),
+                Colour::White => (strength_b, strength_a),
+            };
+            let game = play_to_end(DefaultGame::new(), black_strength, white_strength, &mut rng);
+            let (black_score, white_score) = game.scores();
+            let margin = (black_score - white_score) * a_colour.sign();
+            match margin {
+                m if m > 0 => a_wins += 1,
+                m if m < 0 => b_wins += 1,
+                _ => draws += 1,
+            }
+            total_margin += margin as i64;
+        }
+    }
+
+    let dict = PyDict::new_bound(py);
+    dict.set_item("a_wins", a_wins)?;
+    dict.set_item("b_wins", b_wins)?;
+    dict.set_item("draws", draws)?;
+    let games_played = 2 * games;
+    dict.set_item("avg_margin", if games_played > 0 { total_margin as f64 / games_played as f64 } else { 0.0 })?;
+    Ok(dict.into())
+}
+
 /// A Python module implemented in Rust.
 #[pymodule]
 fn othello_rust(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyOthelloGame>()?;
+    m.add_class::<PySearchStats>()?;
+    m.add_function(wrap_pyfunction!(benchmark_search, m)?)?;
+    m.add_function(wrap_pyfunction!(compare_ais, m)?)?;
+    m.add_function(wrap_pyfunction!(positional_weights, m)?)?;
+    m.add_function(wrap_pyfunction!(perft, m)?)?;
     Ok(())
 }
@@ -0,0 +1,89 @@
+"""Tests of the Python bindings.  Build them first with `maturin develop`, then run
+`python -m unittest discover tests`."""
+
+import unittest
+
+from othello_rust import OthelloGame
+
+
+def finished_game():
+    game = OthelloGame()
+    while not game.is_game_over:
+        game.ai_move(0)
+    return game
+
+
+class AiMoveIfAboveTest(unittest.TestCase):
+    def test_invalid_strength_raises_at_game_over(self):
+        game = finished_game()
+        with self.assertRaises(ValueError):
+            game.ai_move_if_above(0, 0)
+
+    def test_game_over_returns_none(self):
+        self.assertIsNone(finished_game().ai_move_if_above(1, 0))
+
+
+class EvaluateComponentsTest(unittest.TestCase):
+    def test_components_total_the_ai_evaluation(self):
+        game = OthelloGame()
+        game.add_stone(44)
+        for move in game.valid_moves_for(game.next_player):
+            components = game.evaluate_components(move)
+            black_score, white_score = game.scores_after(move)
+            self.assertEqual({"corners": 0, "mobility": 0, "discs": white_score - black_score,
+                              "stability": 0, "total": white_score - black_score}, components)
+
+
+class EvaluateBothTest(unittest.TestCase):
+    def test_matches_ai_score(self):
+        # Play to a position where the only move fills the last square, so the AI's search score
+        # for it is the evaluation of the finished game
+        while True:
+            game = OthelloGame()
+            while sum(game.scores) < 63 and not game.is_game_over:
+                game.ai_move(0)
+            if game.valid_moves_for(game.next_player):
+                break
+        player = game.next_player
+        best_score = game.ai_move_full(1).best_score
+        self.assertTrue(game.is_game_over)
+        black_eval, white_eval = game.evaluate_both()
+        self.assertEqual(best_score, [black_eval, white_eval][player - 1])
+        self.assertEqual(-black_eval, white_eval)
+
+
+class TokenTest(unittest.TestCase):
+    def test_round_trip(self):
+        game = OthelloGame()
+        for move in [44, 43, 30]:
+            game.add_stone(move)
+        self.assertEqual("WKzw", game.to_token())
+        self.assertEqual([44, 43, 30], OthelloGame.from_token("WKzw").list_moves)
+        self.assertEqual([], OthelloGame.from_token("").list_moves)
+
+    def test_truncated_token_raises(self):
+        for token in ["WKz", "W"]:
+            with self.assertRaises(ValueError):
+                OthelloGame.from_token(token)
+
+    def test_trailing_junk_raises(self):
+        for token in ["WKzwB", "WKzwAA", "WKzx", "_"]:
+            with self.assertRaises(ValueError):
+                OthelloGame.from_token(token)
+
+
+class CheckpointTest(unittest.TestCase):
+    def test_children_have_no_checkpoints(self):
+        game = OthelloGame()
+        # The last stone placed in a game always leads to a child, so this stops before game over
+        while not game.near_terminal_children():
+            game.ai_move(0)
+        checkpoint = game.checkpoint()
+        _, child = game.near_terminal_children()[0]
+        with self.assertRaises(ValueError):
+            child.restore(checkpoint)
+        game.restore(checkpoint)
+
+
+if __name__ == "__main__":
+    unittest.main()